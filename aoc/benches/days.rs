@@ -0,0 +1,69 @@
+use aoc_common::{InputSource, Part, Solution};
+use criterion::{criterion_group, criterion_main, Criterion, SamplingMode, Throughput};
+use day_05::layout::SeatLayout;
+use std::hint::black_box;
+use std::time::Duration;
+
+// Benchmarks parsing and each part separately, against the day's bundled input. Slow days take
+// seconds per iteration, so they're given the fewest samples criterion allows.
+fn bench_day<S: Solution>(c: &mut Criterion, slow: bool) {
+    let raw_input = InputSource::Bundled.read::<S>().unwrap();
+    let input = S::parse(&raw_input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day_{:02}", S::DAY));
+    if slow {
+        group
+            .sample_size(10)
+            .sampling_mode(SamplingMode::Flat)
+            .measurement_time(Duration::from_secs(60));
+    }
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&raw_input))));
+    for part in Part::ALL {
+        group.bench_function(part.to_string(), |b| {
+            b.iter(|| S::solve(black_box(&input), part))
+        });
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_01::Day01>(c, false);
+    bench_day::<day_02::Day02>(c, false);
+    bench_day::<day_03::Day03>(c, false);
+    bench_day::<day_04::Day04>(c, false);
+    bench_day::<day_05::Day05>(c, false);
+    bench_day::<day_06::Day06>(c, false);
+    bench_day::<day_07::Day07>(c, false);
+    bench_day::<day_08::Day08>(c, false);
+    bench_day::<day_09::Day09>(c, false);
+    bench_day::<day_10::Day10>(c, false);
+    bench_day::<day_11::Day11>(c, false);
+    bench_day::<day_12::Day12>(c, false);
+    bench_day::<day_13::Day13>(c, false);
+    bench_day::<day_14::Day14>(c, false);
+    bench_day::<day_15::Day15>(c, true);
+    bench_day::<day_16::Day16>(c, false);
+    bench_day::<day_17::Day17>(c, false);
+    bench_day::<day_18::Day18>(c, false);
+}
+
+// Decoding a large batch of boarding passes at once, reported in passes per second
+fn bulk_boarding_passes(c: &mut Criterion) {
+    const PASSES: usize = 100_000;
+    let layout = SeatLayout::PUZZLE;
+    let input: String = (0..PASSES)
+        .map(|i| format!("{}\n", layout.boarding_pass(i % 1024)))
+        .collect();
+
+    let mut group = c.benchmark_group("day_05_bulk");
+    group.throughput(Throughput::Elements(PASSES as u64));
+    group.bench_function("decode_all", |b| {
+        b.iter(|| layout.decode_all(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, days, bulk_boarding_passes);
+criterion_main!(benches);
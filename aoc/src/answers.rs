@@ -0,0 +1,60 @@
+use aoc_common::Part;
+use std::fs;
+use toml::{Table, Value};
+
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+// The known answers for the bundled inputs, as a table per day ("day_01") with an entry per part
+// ("part_1"). Answers can be written as integers or strings, and are compared as text.
+pub struct Answers {
+    table: Table,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Answers, String> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {}", path, e))?;
+        Answers::parse(&contents).map_err(|e| format!("Couldn't parse {}: {}", path, e))
+    }
+
+    pub fn parse(s: &str) -> Result<Answers, String> {
+        let table: Table = s.parse().map_err(|e: toml::de::Error| e.to_string())?;
+        Ok(Answers { table })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        let answer = self
+            .table
+            .get(&format!("day_{:02}", day))?
+            .get(part.to_string())?;
+
+        match answer {
+            Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_get() {
+        let answers = Answers::parse("[day_01]\npart_1 = 514579\npart_2 = \"241861950\"").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("514579".to_string()));
+        assert_eq!(answers.get(1, Part::Two), Some("241861950".to_string()));
+        assert_eq!(answers.get(2, Part::One), None);
+
+        assert!(Answers::parse("[day_01\n").is_err());
+    }
+
+    #[test]
+    fn test_default_answers() {
+        let answers = Answers::load(DEFAULT_PATH).unwrap();
+        assert_eq!(
+            answers.get(18, Part::Two),
+            Some("34646237037193".to_string())
+        );
+    }
+}
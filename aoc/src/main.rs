@@ -0,0 +1,493 @@
+use crate::answers::Answers;
+use aoc_common::{InputError, InputSource, Part, Solution};
+use std::process;
+use std::time::{Duration, Instant};
+
+mod answers;
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--time | --json]
+    aoc verify [day|all] [--answers <path>]
+    aoc generate <day> [--seed <n>] [--size <n>]";
+
+#[derive(Eq, PartialEq, Debug)]
+struct Options {
+    parts: Vec<Part>,
+    source: InputSource,
+    format: Format,
+}
+
+// How answers are printed: on their own, along with how long parsing and each part took, or as one
+// JSON object per part (which always includes the timings)
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum Format {
+    Plain,
+    Timed,
+    Json,
+}
+
+// A day's answers, along with how long it took to get them. Reading the input isn't included in
+// the parse time.
+struct Solved {
+    parse_time: Duration,
+    answers: Vec<(Part, String, Duration)>,
+}
+
+// Bad arguments get the usage printed along with them, whereas a failure while running doesn't
+#[derive(Eq, PartialEq, Debug)]
+enum Error {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Usage(message)
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match run_command(&args) {
+        Ok(()) => (),
+        Err(Error::Usage(message)) => {
+            eprintln!("{}\n{}", message, USAGE);
+            process::exit(1);
+        }
+        Err(Error::Failed(message)) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_command(args: &[String]) -> Result<(), Error> {
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => {
+            let days = parse_days(args.get(1))?;
+            let options = parse_options(args.get(2..).unwrap_or_default())?;
+
+            if days.len() > 1 && options.source != InputSource::Bundled {
+                return Err(Error::Usage(
+                    "--input can only be used with a single day".to_string(),
+                ));
+            }
+
+            for day in days {
+                let solved = solve_day(day, &options)?;
+                print_solved(day, &solved, options.format);
+            }
+
+            Ok(())
+        }
+        Some("verify") => {
+            let (days, answers_path) = parse_verify_args(args.get(1..).unwrap_or_default())?;
+            let answers = Answers::load(&answers_path).map_err(Error::Failed)?;
+
+            verify(&days, &answers)
+        }
+        Some("generate") => {
+            let (generate, default_size) = generator(parse_day(args.get(1))?)?;
+            let (seed, size) = parse_generate_options(args.get(2..).unwrap_or_default())?;
+
+            print!("{}", generate(seed, size.unwrap_or(default_size)));
+            Ok(())
+        }
+        Some(command) => Err(Error::Usage(format!("Unknown command: '{}'", command))),
+        None => Err(Error::Usage("Missing command".to_string())),
+    }
+}
+
+// Either a single day number, or "all" for every day
+fn parse_days(arg: Option<&String>) -> Result<Vec<u8>, String> {
+    match arg.map(|s| s.as_str()) {
+        Some("all") => Ok((1..=18).collect()),
+        Some(day) => day
+            .parse()
+            .map(|day| vec![day])
+            .map_err(|_| format!("Invalid day: '{}'", day)),
+        None => Err("Missing day".to_string()),
+    }
+}
+
+fn parse_day(arg: Option<&String>) -> Result<u8, String> {
+    let arg = arg.ok_or_else(|| "Missing day".to_string())?;
+    arg.parse().map_err(|_| format!("Invalid day: '{}'", arg))
+}
+
+// Both parts are run against the bundled input unless "--part <1|2>" or "--input <path|->" are
+// given, and only the answers are printed unless "--time" or "--json" are given
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        parts: Part::ALL.to_vec(),
+        source: InputSource::Bundled,
+        format: Format::Plain,
+    };
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", flag))
+        };
+
+        match flag.as_str() {
+            "--part" => options.parts = vec![value()?.parse()?],
+            "--input" => options.source = InputSource::from_arg(Some(value()?)),
+            "--time" if options.format != Format::Json => options.format = Format::Timed,
+            "--time" => (),
+            "--json" => options.format = Format::Json,
+            _ => return Err(format!("Unknown option: '{}'", flag)),
+        }
+    }
+
+    Ok(options)
+}
+
+// Verifying checks every day unless one is given, against the answers file in this crate unless
+// "--answers <path>" is given
+fn parse_verify_args(args: &[String]) -> Result<(Vec<u8>, String), String> {
+    let (days, mut args) = match args.first() {
+        Some(arg) if !arg.starts_with("--") => (parse_days(Some(arg))?, args[1..].iter()),
+        _ => ((1..=18).collect(), args.iter()),
+    };
+    let mut answers_path = answers::DEFAULT_PATH.to_string();
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--answers" => answers_path = value.clone(),
+            _ => return Err(format!("Unknown option: '{}'", flag)),
+        }
+    }
+
+    Ok((days, answers_path))
+}
+
+// Inputs are generated from seed 0 at the size of the real input unless "--seed <n>" or
+// "--size <n>" are given
+fn parse_generate_options(args: &[String]) -> Result<(u64, Option<usize>), String> {
+    let mut seed = 0;
+    let mut size = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        let invalid = |_| format!("Invalid value for {}: '{}'", flag, value);
+
+        match flag.as_str() {
+            "--seed" => seed = value.parse().map_err(invalid)?,
+            "--size" => size = Some(value.parse().map_err(invalid)?),
+            _ => return Err(format!("Unknown option: '{}'", flag)),
+        }
+    }
+
+    Ok((seed, size))
+}
+
+// Solves both parts of each day against the bundled input, and compares them with the known
+// answers. Every day is checked before failing, so that all of the regressions get reported.
+fn verify(days: &[u8], answers: &Answers) -> Result<(), Error> {
+    let options = Options {
+        parts: Part::ALL.to_vec(),
+        source: InputSource::Bundled,
+        format: Format::Plain,
+    };
+    let mut checked = 0;
+    let mut failures = 0;
+
+    for &day in days {
+        let solved = match solve_day(day, &options) {
+            Ok(solved) => solved,
+            Err(Error::Failed(message)) => {
+                println!("day_{:02}: {}", day, message);
+                failures += 1;
+                continue;
+            }
+            Err(usage) => return Err(usage),
+        };
+
+        for (part, answer, _time) in solved.answers {
+            match answers.get(day, part) {
+                Some(expected) if expected == answer => {
+                    println!("day_{:02} {}: ok", day, part);
+                    checked += 1;
+                }
+                Some(expected) => {
+                    println!(
+                        "day_{:02} {}: expected {}, got {}",
+                        day, part, expected, answer
+                    );
+                    checked += 1;
+                    failures += 1;
+                }
+                None => println!("day_{:02} {}: no known answer ({})", day, part, answer),
+            }
+        }
+    }
+
+    if failures == 0 {
+        println!("All {} answers match", checked);
+        Ok(())
+    } else {
+        Err(Error::Failed(format!(
+            "Verification failed with {} problem(s)",
+            failures
+        )))
+    }
+}
+
+fn solve_day(day: u8, options: &Options) -> Result<Solved, Error> {
+    match day {
+        1 => solve::<day_01::Day01>(options),
+        2 => solve::<day_02::Day02>(options),
+        3 => solve::<day_03::Day03>(options),
+        4 => solve::<day_04::Day04>(options),
+        5 => solve::<day_05::Day05>(options),
+        6 => solve::<day_06::Day06>(options),
+        7 => solve::<day_07::Day07>(options),
+        8 => solve::<day_08::Day08>(options),
+        9 => solve::<day_09::Day09>(options),
+        10 => solve::<day_10::Day10>(options),
+        11 => solve::<day_11::Day11>(options),
+        12 => solve::<day_12::Day12>(options),
+        13 => solve::<day_13::Day13>(options),
+        14 => solve::<day_14::Day14>(options),
+        15 => solve::<day_15::Day15>(options),
+        16 => solve::<day_16::Day16>(options),
+        17 => solve::<day_17::Day17>(options),
+        18 => solve::<day_18::Day18>(options),
+        _ => Err(Error::Usage(format!("Unknown day: {}", day))),
+    }
+}
+
+// Takes a seed and a size
+type Generator = fn(u64, usize) -> String;
+
+// Each day's input generator, along with the size of its real input
+fn generator(day: u8) -> Result<(Generator, usize), Error> {
+    match day {
+        1 => Ok((day_01::generator::generate, day_01::generator::DEFAULT_SIZE)),
+        2 => Ok((day_02::generator::generate, day_02::generator::DEFAULT_SIZE)),
+        3 => Ok((day_03::generator::generate, day_03::generator::DEFAULT_SIZE)),
+        4 => Ok((day_04::generator::generate, day_04::generator::DEFAULT_SIZE)),
+        5 => Ok((day_05::generator::generate, day_05::generator::DEFAULT_SIZE)),
+        6 => Ok((day_06::generator::generate, day_06::generator::DEFAULT_SIZE)),
+        7 => Ok((day_07::generator::generate, day_07::generator::DEFAULT_SIZE)),
+        8 => Ok((day_08::generator::generate, day_08::generator::DEFAULT_SIZE)),
+        9 => Ok((day_09::generator::generate, day_09::generator::DEFAULT_SIZE)),
+        10 => Ok((day_10::generator::generate, day_10::generator::DEFAULT_SIZE)),
+        11 => Ok((day_11::generator::generate, day_11::generator::DEFAULT_SIZE)),
+        12 => Ok((day_12::generator::generate, day_12::generator::DEFAULT_SIZE)),
+        13 => Ok((day_13::generator::generate, day_13::generator::DEFAULT_SIZE)),
+        14 => Ok((day_14::generator::generate, day_14::generator::DEFAULT_SIZE)),
+        15 => Ok((day_15::generator::generate, day_15::generator::DEFAULT_SIZE)),
+        16 => Ok((day_16::generator::generate, day_16::generator::DEFAULT_SIZE)),
+        17 => Ok((day_17::generator::generate, day_17::generator::DEFAULT_SIZE)),
+        18 => Ok((day_18::generator::generate, day_18::generator::DEFAULT_SIZE)),
+        _ => Err(Error::Usage(format!("Unknown day: {}", day))),
+    }
+}
+
+fn solve<S: Solution>(options: &Options) -> Result<Solved, Error> {
+    let failed = |e: InputError| Error::Failed(e.to_string());
+    let raw_input = options.source.read::<S>().map_err(|e| failed(e.into()))?;
+
+    let start = Instant::now();
+    let input = S::parse(&raw_input).map_err(|e| failed(e.into()))?;
+    let parse_time = start.elapsed();
+
+    let answers = options
+        .parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = S::solve(&input, part).to_string();
+            (part, answer, start.elapsed())
+        })
+        .collect();
+
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+fn print_solved(day: u8, solved: &Solved, format: Format) {
+    match format {
+        Format::Plain => {
+            println!("day_{:02}", day);
+            for (part, answer, _time) in &solved.answers {
+                println!("{}: {}", part, answer);
+            }
+        }
+        Format::Timed => {
+            println!("day_{:02}", day);
+            println!("parse: {:?}", solved.parse_time);
+            for (part, answer, time) in &solved.answers {
+                println!("{}: {} ({:?})", part, answer, time);
+            }
+        }
+        Format::Json => {
+            for (part, answer, time) in &solved.answers {
+                println!(
+                    "{}",
+                    json_line(day, *part, answer, *time, solved.parse_time)
+                );
+            }
+        }
+    }
+}
+
+// Answers that are whole numbers are written as JSON numbers, and anything else (including numbers
+// that wouldn't be written the same way, like "007") as strings
+fn json_line(day: u8, part: Part, answer: &str, time: Duration, parse_time: Duration) -> String {
+    serde_json::json!({
+        "day": day,
+        "part": part.number(),
+        "answer": json_answer(answer),
+        "micros": time.as_micros() as u64,
+        "parse_micros": parse_time.as_micros() as u64,
+    })
+    .to_string()
+}
+
+fn json_answer(answer: &str) -> serde_json::Value {
+    match (answer.parse::<u64>(), answer.parse::<i64>()) {
+        (Ok(n), _) if n.to_string() == answer => n.into(),
+        (_, Ok(n)) if n.to_string() == answer => n.into(),
+        _ => answer.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days(Some(&"7".to_string())), Ok(vec![7]));
+        assert_eq!(parse_days(Some(&"all".to_string())).unwrap().len(), 18);
+        assert!(parse_days(Some(&"seven".to_string())).is_err());
+        assert!(parse_days(None).is_err());
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(
+            parse_options(&args("")),
+            Ok(Options {
+                parts: vec![Part::One, Part::Two],
+                source: InputSource::Bundled,
+                format: Format::Plain,
+            })
+        );
+        assert_eq!(
+            parse_options(&args("--part 2 --input -")),
+            Ok(Options {
+                parts: vec![Part::Two],
+                source: InputSource::Stdin,
+                format: Format::Plain,
+            })
+        );
+        assert_eq!(
+            parse_options(&args("--input other.txt")).unwrap().source,
+            InputSource::File("other.txt".into())
+        );
+        assert!(parse_options(&args("--part 3")).is_err());
+        assert!(parse_options(&args("--part")).is_err());
+        assert!(parse_options(&args("--bogus 1")).is_err());
+
+        assert_eq!(
+            parse_options(&args("--time --part 1")).unwrap().format,
+            Format::Timed
+        );
+        assert_eq!(
+            parse_options(&args("--json --time")).unwrap().format,
+            Format::Json
+        );
+    }
+
+    #[test]
+    fn test_json_line() {
+        assert_eq!(
+            json_line(
+                8,
+                Part::Two,
+                "672",
+                Duration::from_micros(35),
+                Duration::from_millis(2)
+            ),
+            r#"{"answer":672,"day":8,"micros":35,"parse_micros":2000,"part":2}"#
+        );
+        assert_eq!(
+            json_line(1, Part::One, "-12", Duration::ZERO, Duration::ZERO),
+            r#"{"answer":-12,"day":1,"micros":0,"parse_micros":0,"part":1}"#
+        );
+        assert_eq!(
+            json_line(1, Part::One, "007", Duration::ZERO, Duration::ZERO),
+            r#"{"answer":"007","day":1,"micros":0,"parse_micros":0,"part":1}"#
+        );
+        assert_eq!(
+            json_line(
+                1,
+                Part::Two,
+                "No 3 of the 3 entries",
+                Duration::ZERO,
+                Duration::ZERO
+            ),
+            r#"{"answer":"No 3 of the 3 entries","day":1,"micros":0,"parse_micros":0,"part":2}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        let (days, path) = parse_verify_args(&args("")).unwrap();
+        assert_eq!(days.len(), 18);
+        assert_eq!(path, answers::DEFAULT_PATH);
+
+        assert_eq!(
+            parse_verify_args(&args("7 --answers other.toml")),
+            Ok((vec![7], "other.toml".to_string()))
+        );
+        assert_eq!(
+            parse_verify_args(&args("--answers other.toml"))
+                .unwrap()
+                .0
+                .len(),
+            18
+        );
+        assert!(parse_verify_args(&args("--answers")).is_err());
+        assert!(parse_verify_args(&args("seven")).is_err());
+    }
+
+    #[test]
+    fn test_parse_generate_options() {
+        assert_eq!(parse_generate_options(&args("")), Ok((0, None)));
+        assert_eq!(
+            parse_generate_options(&args("--size 10 --seed 3")),
+            Ok((3, Some(10)))
+        );
+        assert!(parse_generate_options(&args("--size ten")).is_err());
+        assert!(parse_generate_options(&args("--seed")).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("[day_06]\npart_1 = 6273\npart_2 = 3254").unwrap();
+        assert_eq!(verify(&[6], &answers), Ok(()));
+
+        let answers = Answers::parse("[day_06]\npart_1 = 6273\npart_2 = 1").unwrap();
+        assert!(matches!(verify(&[6], &answers), Err(Error::Failed(_))));
+    }
+}
@@ -0,0 +1,204 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+
+// Points at the offending text in the puzzle input. Lines and columns are 1-indexed, and columns
+// count characters rather than bytes.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, reason: impl Display) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    // For errors found while parsing a single line; offending must be a slice of line, which is
+    // how the column is worked out. The error can be moved to the line's real position in the
+    // input with #offset_lines.
+    pub fn in_line(line: &str, offending: &str, reason: impl Display) -> ParseError {
+        ParseError::new(1, column_of(line, offending), offending, reason)
+    }
+
+    // Errors from parsing part of a line only know their column within that part; this shifts
+    // them over to where the part sits within the whole line (part must be a slice of line)
+    pub fn within(mut self, line: &str, part: &str) -> ParseError {
+        self.column += column_of(line, part) - 1;
+        self
+    }
+
+    // Errors from parsing one line (or block of lines) don't know where that line sits within the
+    // whole input; this shifts them down by the given number of lines
+    pub fn offset_lines(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} ('{}')",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Anything that can go wrong while loading a day's input
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "Couldn't read input: {}", e),
+            InputError::Parse(e) => write!(f, "Couldn't parse input: {}", e),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(e: ParseError) -> Self {
+        InputError::Parse(e)
+    }
+}
+
+// The 1-indexed column at which part starts within line, or 1 if it isn't a slice of line
+fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .filter(|&offset| offset <= line.len() && line.is_char_boundary(offset))
+        .unwrap_or(0);
+
+    line[..offset].chars().count() + 1
+}
+
+// Parses a number, reporting the whole of s if it isn't one
+pub fn parse_number<T>(s: &str) -> Result<T, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    s.parse()
+        .map_err(|e| ParseError::in_line(s, s, format!("Invalid number: {}", e)))
+}
+
+// Parses each line of the input, pointing any error at its line within the whole input
+pub fn parse_lines<T, F>(input: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| e.offset_lines(idx)))
+        .collect()
+}
+
+// Splits the input into blocks separated by blank lines, along with the number of lines that come
+// before each block (to be used with ParseError#offset_lines). Any number of blank lines can
+// separate two blocks, lines with only whitespace on them count as blank, and lines can end with
+// either "\n" or "\r\n".
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = vec![];
+    // The first line of the current block, and where it starts and ends in the input
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut start = 0;
+
+    for (idx, raw_line) in input.split_inclusive('\n').enumerate() {
+        let line = raw_line.trim_end_matches('\n').trim_end_matches('\r');
+        let end = start + line.len();
+
+        if line.trim().is_empty() {
+            if let Some((offset, block_start, block_end)) = current.take() {
+                blocks.push((offset, &input[block_start..block_end]));
+            }
+        } else if let Some((_, _, block_end)) = &mut current {
+            *block_end = end;
+        } else {
+            current = Some((idx, start, end));
+        }
+
+        start += raw_line.len();
+    }
+
+    if let Some((offset, block_start, block_end)) = current {
+        blocks.push((offset, &input[block_start..block_end]));
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_in_line() {
+        let line = "mem[8] = eleven";
+        let error = ParseError::in_line(line, &line[9..], "Invalid value");
+        assert_eq!(error, ParseError::new(1, 10, "eleven", "Invalid value"));
+
+        let error = ParseError::in_line(line, "elsewhere", "Not a slice");
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_parse_error_within() {
+        let line = "1-3 a: abcde";
+        let error = parse_number::<usize>(&line[4..5])
+            .unwrap_err()
+            .within(line, &line[4..5]);
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n3\n", parse_number), Ok(vec![1, 2, 3]));
+
+        let error = parse_lines::<usize, _>("1\n2\nthree", parse_number).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "three");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: Invalid number: invalid digit found in string ('three')"
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        assert_eq!(
+            blocks("a\nb\n\nc\n\nd\ne\nf\n"),
+            vec![(0, "a\nb"), (3, "c"), (5, "d\ne\nf")]
+        );
+        assert_eq!(
+            blocks("\na\r\nb\r\n\r\n  \r\n\nc \n \t\n"),
+            vec![(1, "a\r\nb"), (6, "c ")]
+        );
+        assert_eq!(blocks(""), vec![]);
+    }
+}
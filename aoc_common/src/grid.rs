@@ -0,0 +1,237 @@
+use crate::ParseError;
+use std::fmt::{self, Display};
+
+// (x, y), where x grows to the right and y grows downwards
+pub type Coord = (isize, isize);
+
+pub const ORTHOGONAL: [Coord; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+pub const ALL_DIRECTIONS: [Coord; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// A rectangular grid of cells, stored row by row
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Grid cells don't fit");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Builds a grid from lines of characters, using to_cell to convert each character. Every line
+    // must be the same length.
+    pub fn parse<F>(s: &str, to_cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in s.lines().enumerate() {
+            let mut line_width = 0;
+
+            for (x, char) in line.chars().enumerate() {
+                let cell = to_cell(char).ok_or_else(|| {
+                    ParseError::new(y + 1, x + 1, &char.to_string(), "Unknown character")
+                })?;
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        1,
+                        line,
+                        format!("Expected {} characters, not {}", width, line_width),
+                    ));
+                }
+                _ => (),
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index(coord).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index(coord).map(move |idx| &mut self.cells[idx])
+    }
+
+    // The grid repeats forever in every direction
+    pub fn get_wrapping(&self, (x, y): Coord) -> &T {
+        let x = x.rem_euclid(self.width as isize);
+        let y = y.rem_euclid(self.height as isize);
+        &self.cells[y as usize * self.width + x as usize]
+    }
+
+    pub fn set(&mut self, coord: Coord, value: T) {
+        let cell = self.get_mut(coord).expect("Coord is outside of the grid");
+        *cell = value;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (((idx % width) as isize, (idx / width) as isize), cell))
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Coord, &T) -> U,
+    {
+        Grid::new(
+            self.width,
+            self.height,
+            self.iter().map(|(coord, cell)| f(coord, cell)).collect(),
+        )
+    }
+
+    // Up, down, left and right; anything outside the grid is skipped
+    pub fn neighbors_4(&self, coord: Coord) -> impl Iterator<Item = &T> {
+        self.neighbors(coord, &ORTHOGONAL)
+    }
+
+    // Same, plus diagonals
+    pub fn neighbors_8(&self, coord: Coord) -> impl Iterator<Item = &T> {
+        self.neighbors(coord, &ALL_DIRECTIONS)
+    }
+
+    // Every cell from coord (exclusive) heading in the (dx, dy) direction, until falling off the
+    // edge of the grid
+    pub fn ray(&self, (x, y): Coord, (dx, dy): Coord) -> impl Iterator<Item = &T> {
+        (1..)
+            .map(move |step| (x + dx * step, y + dy * step))
+            .map(move |coord| self.get(coord))
+            .take_while(|cell| cell.is_some())
+            .flatten()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        (x, y): Coord,
+        directions: &'a [Coord],
+    ) -> impl Iterator<Item = &'a T> {
+        directions
+            .iter()
+            .filter_map(move |(dx, dy)| self.get((x + dx, y + dy)))
+    }
+
+    fn index(&self, coord: Coord) -> Option<usize> {
+        if self.contains(coord) {
+            Some(coord.1 as usize * self.width + coord.0 as usize)
+        } else {
+            None
+        }
+    }
+}
+
+// Each cell is expected to render as a single character, so that the output looks like the input
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("#..\n.#.\n..#\n#.#", Some).unwrap()
+    }
+
+    #[test]
+    fn test_grid_parse() {
+        let grid = example();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 4);
+        assert_eq!(grid.get((1, 1)), Some(&'#'));
+        assert_eq!(grid.get((2, 1)), Some(&'.'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+
+        let error = Grid::parse("..\n.x", |c| Some(c).filter(|c| *c == '.')).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "x", "Unknown character"));
+        assert!(Grid::parse("..\n.", Some).is_err());
+    }
+
+    #[test]
+    fn test_grid_get_wrapping() {
+        let grid = example();
+        assert_eq!(grid.get_wrapping((4, 1)), &'#');
+        assert_eq!(grid.get_wrapping((-1, 3)), &'#');
+        assert_eq!(grid.get_wrapping((0, 5)), &'.');
+    }
+
+    #[test]
+    fn test_grid_neighbors() {
+        let grid = example();
+        assert_eq!(grid.neighbors_4((0, 0)).collect::<String>(), "..");
+        assert_eq!(grid.neighbors_8((0, 0)).collect::<String>(), "..#");
+        assert_eq!(grid.neighbors_8((1, 1)).filter(|c| **c == '#').count(), 2);
+    }
+
+    #[test]
+    fn test_grid_ray() {
+        let grid = example();
+        assert_eq!(grid.ray((0, 0), (1, 1)).collect::<String>(), "##");
+        assert_eq!(grid.ray((2, 3), (0, -1)).collect::<String>(), "#..");
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_grid_display() {
+        let mut grid = example();
+        grid.set((1, 0), '#');
+        assert_eq!(grid.to_string(), "##.\n.#.\n..#\n#.#");
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{InputError, Solution};
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+// Where to read a day's puzzle input from. The bundled file is the input.txt that lives in the
+// day's crate; it's read at runtime, so it can be swapped out without recompiling. Its path is
+// fixed when the day is built, so a binary that's moved to another machine has to be given its
+// input instead.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum InputSource {
+    Bundled,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    // No argument means the bundled input, and "-" means stdin
+    pub fn from_arg(arg: Option<&str>) -> InputSource {
+        match arg {
+            None => InputSource::Bundled,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.into()),
+        }
+    }
+
+    pub fn read<S: Solution>(&self) -> io::Result<String> {
+        let result = match self {
+            InputSource::Bundled => fs::read_to_string(S::INPUT_PATH),
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|e| io::Error::new(e.kind(), self.describe_error::<S>(&e)))
+    }
+
+    pub fn load<S: Solution>(&self) -> Result<S::Input, InputError> {
+        Ok(S::parse(&self.read::<S>()?)?)
+    }
+
+    fn describe_error<S: Solution>(&self, e: &io::Error) -> String {
+        match self {
+            InputSource::Bundled if e.kind() == io::ErrorKind::NotFound => format!(
+                "No bundled input at {}, which is where it was when this was built \
+                 (give an input path, or - for stdin)",
+                S::INPUT_PATH
+            ),
+            InputSource::Bundled => format!("{}: {}", S::INPUT_PATH, e),
+            _ => format!("{}: {}", self, e),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Bundled => write!(f, "bundled input"),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Missing;
+
+    impl Solution for Missing {
+        const DAY: u8 = 0;
+        const INPUT_PATH: &'static str = "/nowhere/day_00/src/input.txt";
+
+        type Input = String;
+        type Answer = String;
+
+        fn parse(input: &str) -> Result<String, crate::ParseError> {
+            Ok(input.to_string())
+        }
+
+        fn part_1(input: &String) -> String {
+            input.clone()
+        }
+
+        fn part_2(input: &String) -> String {
+            input.clone()
+        }
+    }
+
+    #[test]
+    fn test_missing_bundled_input() {
+        let error = InputSource::Bundled.read::<Missing>().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert_eq!(
+            error.to_string(),
+            "No bundled input at /nowhere/day_00/src/input.txt, which is where it was when this \
+             was built (give an input path, or - for stdin)"
+        );
+
+        let error = InputSource::from_arg(Some("/nowhere/input.txt"))
+            .read::<Missing>()
+            .unwrap_err();
+        assert!(error.to_string().starts_with("/nowhere/input.txt: "));
+    }
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Bundled);
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("input.txt")),
+            InputSource::File("input.txt".into())
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod error;
+pub mod grid;
+mod input;
+mod solution;
+
+pub use crate::error::{blocks, parse_lines, parse_number, InputError, ParseError};
+pub use crate::grid::Grid;
+pub use crate::input::InputSource;
+pub use crate::solution::{main, run, Part, Solution};
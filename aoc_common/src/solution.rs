@@ -0,0 +1,97 @@
+use crate::{InputError, InputSource, ParseError};
+use std::fmt::{self, Display};
+use std::process;
+use std::str::FromStr;
+
+// Every day implements this so that the runners can parse its input and solve either part without
+// knowing anything else about the puzzle. INPUT_PATH points at the day's bundled input.txt, which
+// is used when no other input is given.
+pub trait Solution {
+    const DAY: u8;
+    const INPUT_PATH: &'static str;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Self::Answer;
+    fn part_2(input: &Self::Input) -> Self::Answer;
+
+    fn solve(input: &Self::Input, part: Part) -> Self::Answer {
+        match part {
+            Part::One => Self::part_1(input),
+            Part::Two => Self::part_2(input),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part_1"),
+            Part::Two => write!(f, "part_2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part: '{}'", s)),
+        }
+    }
+}
+
+// Reads and parses the input, then prints the answer to each of the given parts
+pub fn run<S: Solution>(source: &InputSource, parts: &[Part]) -> Result<(), InputError> {
+    let input = source.load::<S>()?;
+
+    for &part in parts {
+        println!("{}: {}", part, S::solve(&input, part));
+    }
+
+    Ok(())
+}
+
+// Entry point for each day's binary, which takes an optional input path ("-" for stdin)
+pub fn main<S: Solution>() {
+    let arg = std::env::args().nth(1);
+
+    if let Err(e) = run::<S>(&InputSource::from_arg(arg.as_deref()), &Part::ALL) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
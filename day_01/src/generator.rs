@@ -0,0 +1,93 @@
+use crate::k_sum::{all_k_sums, EntryUse};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+pub const DEFAULT_SIZE: usize = 200;
+
+// An expense report with size entries (at least 5). A pair and a triple that sum to 2020 are
+// planted somewhere in it, and they're the only ones: every other entry is over 1010, so none of
+// them can pair up with each other, and none of them make 2020 with one or two planted entries.
+pub fn generate(seed: u64, size: usize) -> String {
+    let (entries, _pair, _triple) = expense_report(seed, size);
+
+    entries.iter().map(|entry| format!("{}\n", entry)).collect()
+}
+
+// The entries, along with the pair and the triple that were planted in them
+fn expense_report(seed: u64, size: usize) -> (Vec<usize>, [usize; 2], [usize; 3]) {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // The planted entries can't make any other pairs or triples between themselves
+    let (pair, triple, mut entries) = loop {
+        let pair = rng.gen_range(20..=1000);
+        let pair = [pair, 2020 - pair];
+        let a = rng.gen_range(20..=600);
+        let b = rng.gen_range(20..=600);
+        let triple = [a, b, 2020 - a - b];
+
+        let planted: Vec<usize> = pair.iter().chain(&triple).cloned().collect();
+        if sums_to_2020(&planted, 2) == 1 && sums_to_2020(&planted, 3) == 1 {
+            break (pair, triple, planted);
+        }
+    };
+
+    // The values that would make 2020 along with one or two of the planted entries
+    let mut unusable = HashSet::new();
+    for (idx, x) in entries.iter().enumerate() {
+        unusable.insert(2020 - x);
+        for y in &entries[(idx + 1)..] {
+            if x + y < 2020 {
+                unusable.insert(2020 - x - y);
+            }
+        }
+    }
+
+    while entries.len() < size {
+        let entry = rng.gen_range(1011..=2020);
+        if !unusable.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    entries.shuffle(&mut rng);
+
+    (entries, pair, triple)
+}
+
+// How many combinations of k different entries sum to 2020
+fn sums_to_2020(entries: &[usize], k: usize) -> usize {
+    all_k_sums(entries, k, 2020, EntryUse::Once).map_or(0, |combinations| combinations.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day01, Report};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let entries = Day01::parse(&generate(1, 50)).unwrap();
+        assert_eq!(entries.len(), 50);
+        assert_eq!(entries, expense_report(1, 50).0);
+
+        // The planted pair and triple are the only ones, so they're what the solutions find
+        for seed in 0..100 {
+            let (entries, pair, triple) = expense_report(seed, DEFAULT_SIZE);
+            assert_eq!(sums_to_2020(&entries, 2), 1, "seed {}", seed);
+            assert_eq!(sums_to_2020(&entries, 3), 1, "seed {}", seed);
+            assert_eq!(
+                Day01::part_1(&entries),
+                Report::Product(pair.iter().product())
+            );
+            assert_eq!(
+                Day01::part_2(&entries),
+                Report::Product(triple.iter().product())
+            );
+        }
+    }
+}
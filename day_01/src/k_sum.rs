@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use EntryUse::*;
+
+// Whether a combination can use the same entry more than once. Two entries that happen to have the
+// same value are always separate entries (with their own indices), so they can both be used either
+// way.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum EntryUse {
+    Once,
+    Repeatable,
+}
+
+// Entries from the expense report that add up to a target. Indices are the positions of those
+// entries within the report, in increasing order (an index shows up more than once if that entry
+// was reused).
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub entries: Vec<usize>,
+}
+
+impl Combination {
+    fn new(numbers: &[usize], indices: &[usize]) -> Combination {
+        Combination {
+            indices: indices.to_vec(),
+            entries: indices.iter().map(|&idx| numbers[idx]).collect(),
+        }
+    }
+
+    // None if the product is too big for a usize
+    pub fn product(&self) -> Option<usize> {
+        self.entries
+            .iter()
+            .try_fold(1_usize, |product, &entry| product.checked_mul(entry))
+    }
+
+    pub fn reuses_entries(&self) -> bool {
+        self.indices.windows(2).any(|pair| pair[0] == pair[1])
+    }
+}
+
+// What was searched when no combination of entries summed to the target, to help see why
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct NoCombination {
+    pub k: usize,
+    pub target: usize,
+    pub entry_use: EntryUse,
+    pub entries: usize,
+    // The smallest and largest sums that k entries can make, if there are enough entries to pick
+    // k of them at all. These are u128s, which any sum of usize entries fits into.
+    pub sum_range: Option<(u128, u128)>,
+}
+
+impl NoCombination {
+    fn new(numbers: &[usize], k: usize, target: usize, entry_use: EntryUse) -> NoCombination {
+        let mut sorted: Vec<u128> = numbers.iter().map(|&n| n as u128).collect();
+        sorted.sort_unstable();
+        let k_wide = k as u128;
+
+        let sum_range = match entry_use {
+            _ if k == 0 => Some((0, 0)),
+            Once if sorted.len() >= k => Some((
+                sorted[..k].iter().sum(),
+                sorted[(sorted.len() - k)..].iter().sum(),
+            )),
+            Repeatable if !sorted.is_empty() => {
+                Some((sorted[0] * k_wide, sorted[sorted.len() - 1] * k_wide))
+            }
+            _ => None,
+        };
+
+        NoCombination {
+            k,
+            target,
+            entry_use,
+            entries: numbers.len(),
+            sum_range,
+        }
+    }
+}
+
+impl Display for NoCombination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reuse = match self.entry_use {
+            Once => "",
+            Repeatable => " (reusing entries)",
+        };
+
+        match self.sum_range {
+            Some((min, max)) => write!(
+                f,
+                "No {} of the {} entries sum to {}{}; sums of {} range from {} to {}",
+                self.k, self.entries, self.target, reuse, self.k, min, max
+            ),
+            None => write!(
+                f,
+                "Can't pick {} of the {} entries to sum to {}",
+                self.k, self.entries, self.target
+            ),
+        }
+    }
+}
+
+// The first combination of k entries found that sums to target. Two entries are found in O(n)
+// time, three in O(n^2), and so on.
+pub fn find_k_sum(
+    numbers: &[usize],
+    k: usize,
+    target: usize,
+    entry_use: EntryUse,
+) -> Result<Combination, NoCombination> {
+    let mut found = None;
+
+    search(
+        numbers,
+        0,
+        k,
+        target,
+        entry_use,
+        &mut vec![],
+        &mut |indices| {
+            found = Some(Combination::new(numbers, indices));
+            false
+        },
+    );
+
+    found.ok_or_else(|| NoCombination::new(numbers, k, target, entry_use))
+}
+
+// Every combination of k entries that sums to target, ordered by their indices
+pub fn all_k_sums(
+    numbers: &[usize],
+    k: usize,
+    target: usize,
+    entry_use: EntryUse,
+) -> Result<Vec<Combination>, NoCombination> {
+    let mut found = vec![];
+
+    search(
+        numbers,
+        0,
+        k,
+        target,
+        entry_use,
+        &mut vec![],
+        &mut |indices| {
+            found.push(Combination::new(numbers, indices));
+            true
+        },
+    );
+
+    if found.is_empty() {
+        return Err(NoCombination::new(numbers, k, target, entry_use));
+    }
+
+    found.sort_by(|a, b| a.indices.cmp(&b.indices));
+    Ok(found)
+}
+
+// Calls visit with chosen plus the indices of each combination of k entries from numbers[start..]
+// that sums to target. Stops as soon as visit returns false, in which case this does too.
+fn search<F>(
+    numbers: &[usize],
+    start: usize,
+    k: usize,
+    target: usize,
+    entry_use: EntryUse,
+    chosen: &mut Vec<usize>,
+    visit: &mut F,
+) -> bool
+where
+    F: FnMut(&[usize]) -> bool,
+{
+    match k {
+        0 => target != 0 || visit(chosen),
+        2 => two_sum(numbers, start, target, entry_use, chosen, visit),
+        _ => {
+            for idx in start..numbers.len() {
+                // Entries can't be negative, so nothing bigger than the target can be part of it
+                if numbers[idx] > target {
+                    continue;
+                }
+
+                let next_start = match entry_use {
+                    Once => idx + 1,
+                    Repeatable => idx,
+                };
+
+                chosen.push(idx);
+                let keep_going = search(
+                    numbers,
+                    next_start,
+                    k - 1,
+                    target - numbers[idx],
+                    entry_use,
+                    chosen,
+                    visit,
+                );
+                chosen.pop();
+
+                if !keep_going {
+                    return false;
+                }
+            }
+
+            true
+        }
+    }
+}
+
+// A single pass over the entries, remembering where each value has been seen so that each entry
+// can look up which earlier entries it pairs with (including itself, if entries can be reused)
+fn two_sum<F>(
+    numbers: &[usize],
+    start: usize,
+    target: usize,
+    entry_use: EntryUse,
+    chosen: &mut Vec<usize>,
+    visit: &mut F,
+) -> bool
+where
+    F: FnMut(&[usize]) -> bool,
+{
+    let mut seen: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for (idx, &number) in numbers.iter().enumerate().skip(start) {
+        if number > target {
+            continue;
+        }
+        if entry_use == Repeatable {
+            seen.entry(number).or_default().push(idx);
+        }
+
+        for &pair_idx in seen.get(&(target - number)).into_iter().flatten() {
+            chosen.extend([pair_idx, idx]);
+            let keep_going = visit(chosen);
+            chosen.truncate(chosen.len() - 2);
+
+            if !keep_going {
+                return false;
+            }
+        }
+
+        if entry_use == Once {
+            seen.entry(number).or_default().push(idx);
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [usize; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn test_find_k_sum() {
+        let pair = find_k_sum(&EXAMPLE, 2, 2020, Once).unwrap();
+        assert_eq!(pair.indices, vec![0, 3]);
+        assert_eq!(pair.entries, vec![1721, 299]);
+        assert_eq!(pair.product(), Some(514579));
+
+        let triple = find_k_sum(&EXAMPLE, 3, 2020, Once).unwrap();
+        assert_eq!(triple.indices, vec![1, 2, 4]);
+        assert_eq!(triple.product(), Some(241861950));
+
+        assert_eq!(find_k_sum(&EXAMPLE, 1, 366, Once).unwrap().indices, vec![2]);
+    }
+
+    #[test]
+    fn test_find_k_sum_not_found() {
+        let error = find_k_sum(&EXAMPLE, 2, 2021, Once).unwrap_err();
+        assert_eq!(error.sum_range, Some((299 + 366, 1721 + 1456)));
+        assert_eq!(
+            error.to_string(),
+            "No 2 of the 6 entries sum to 2021; sums of 2 range from 665 to 3177"
+        );
+
+        let error = find_k_sum(&EXAMPLE, 7, 2020, Once).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Can't pick 7 of the 6 entries to sum to 2020"
+        );
+
+        let error = find_k_sum(&EXAMPLE, 3, 10, Repeatable).unwrap_err();
+        assert_eq!(error.sum_range, Some((299 * 3, 1721 * 3)));
+        assert!(error.to_string().contains("(reusing entries)"));
+    }
+
+    #[test]
+    fn test_find_k_sum_duplicates() {
+        // 1010 only appears once, so it can only pair with itself if entries can be reused
+        let numbers = [1010, 500, 1721];
+        assert!(find_k_sum(&numbers, 2, 2020, Once).is_err());
+        let pair = find_k_sum(&numbers, 2, 2020, Repeatable).unwrap();
+        assert_eq!(pair.indices, vec![0, 0]);
+        assert!(pair.reuses_entries());
+
+        // Two separate entries of 1010 are fine either way
+        let numbers = [1010, 500, 1010];
+        let pair = find_k_sum(&numbers, 2, 2020, Once).unwrap();
+        assert_eq!(pair.indices, vec![0, 2]);
+        assert!(!pair.reuses_entries());
+        let pairs = all_k_sums(&numbers, 2, 2020, Repeatable).unwrap();
+        let indices: Vec<_> = pairs.iter().map(|c| c.indices.clone()).collect();
+        assert_eq!(indices, vec![vec![0, 0], vec![0, 2], vec![2, 2]]);
+    }
+
+    #[test]
+    fn test_all_k_sums() {
+        let numbers = [1, 2, 3, 4, 5, 6];
+        let indices = |k, target| -> Vec<Vec<usize>> {
+            all_k_sums(&numbers, k, target, Once)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|c| c.indices)
+                .collect()
+        };
+
+        assert_eq!(indices(2, 7), vec![vec![0, 5], vec![1, 4], vec![2, 3]]);
+        assert_eq!(
+            indices(3, 9),
+            vec![vec![0, 1, 5], vec![0, 2, 4], vec![1, 2, 3]]
+        );
+        assert_eq!(indices(4, 21).len(), 0);
+        assert_eq!(indices(6, 21), vec![vec![0, 1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_all_k_sums_matches_brute_force() {
+        let numbers = [5, 3, 8, 3, 1, 7, 2, 6, 4, 4];
+
+        for (entry_use, skip) in [(Once, 1), (Repeatable, 0)] {
+            for target in 0..30 {
+                let mut expected = vec![];
+                for a in 0..numbers.len() {
+                    for b in (a + skip)..numbers.len() {
+                        for c in (b + skip)..numbers.len() {
+                            if numbers[a] + numbers[b] + numbers[c] == target {
+                                expected.push(vec![a, b, c]);
+                            }
+                        }
+                    }
+                }
+
+                let found: Vec<Vec<usize>> = all_k_sums(&numbers, 3, target, entry_use)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|c| c.indices)
+                    .collect();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::k_sum::{find_k_sum, Combination, EntryUse, NoCombination};
+use aoc_common::{parse_lines, parse_number, ParseError, Solution};
+use std::fmt::{self, Display};
+
+pub mod generator;
+pub mod k_sum;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<usize>;
+    type Answer = Report;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_lines(input, parse_number)
+    }
+
+    fn part_1(numbers: &Vec<usize>) -> Report {
+        part_1(numbers)
+    }
+
+    fn part_2(numbers: &Vec<usize>) -> Report {
+        part_2(numbers)
+    }
+}
+
+// The product of the entries that sum to 2020, or why there isn't one: either no entries sum to
+// 2020, or the ones that do have a product too big for a usize
+#[derive(Eq, PartialEq, Debug)]
+pub enum Report {
+    Product(usize),
+    Overflow(Combination),
+    NoCombination(NoCombination),
+}
+
+impl From<Result<Combination, NoCombination>> for Report {
+    fn from(result: Result<Combination, NoCombination>) -> Self {
+        match result {
+            Ok(combination) => match combination.product() {
+                Some(product) => Report::Product(product),
+                None => Report::Overflow(combination),
+            },
+            Err(summary) => Report::NoCombination(summary),
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Report::Product(product) => write!(f, "{}", product),
+            Report::Overflow(combination) => {
+                let entries: Vec<String> =
+                    combination.entries.iter().map(|e| e.to_string()).collect();
+                write!(
+                    f,
+                    "The product of {} is too big to calculate",
+                    entries.join(" * ")
+                )
+            }
+            Report::NoCombination(summary) => write!(f, "{}", summary),
+        }
+    }
+}
+
+// Find the two entries that sum to 2020 and then multiply those two numbers together
+pub fn part_1(numbers: &[usize]) -> Report {
+    find_k_sum(numbers, 2, 2020, EntryUse::Once).into()
+}
+
+// Same, but three entries
+pub fn part_2(numbers: &[usize]) -> Report {
+    find_k_sum(numbers, 3, 2020, EntryUse::Once).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let numbers = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(part_1(&numbers), Report::Product(514579));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let numbers = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(part_2(&numbers), Report::Product(241861950));
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(Day01::part_1(&vec![1721, 979, 299]).to_string(), "514579");
+        assert_eq!(
+            Day01::part_2(&vec![1721, 979, 299]).to_string(),
+            "No 3 of the 3 entries sum to 2020; sums of 3 range from 2999 to 2999"
+        );
+    }
+
+    #[test]
+    fn test_report_overflow() {
+        let big = usize::MAX / 2;
+        let report = Report::from(find_k_sum(&[big, 1, big], 2, big * 2, EntryUse::Once));
+        assert_eq!(
+            report.to_string(),
+            format!("The product of {} * {} is too big to calculate", big, big)
+        );
+
+        // The range of sums doesn't overflow either
+        let summary = find_k_sum(&[usize::MAX, 1], 3, 5, EntryUse::Repeatable).unwrap_err();
+        assert_eq!(summary.sum_range, Some((3, usize::MAX as u128 * 3)));
+    }
+}
@@ -1,33 +1,58 @@
-use parse_display::{Display, FromStr};
+use crate::policy::{CountRange, ExactlyOnePosition, PasswordPolicy};
+use aoc_common::{parse_number, ParseError};
+use parse_display::Display;
+use std::str::FromStr;
 
-#[derive(Display, FromStr, PartialEq, Debug)]
+#[derive(Display, Eq, PartialEq, Debug)]
 #[display("{n1}-{n2} {char}: {password}")]
 pub struct Password {
-    n1: usize,
-    n2: usize,
-    char: char,
-    password: String,
+    pub n1: usize,
+    pub n2: usize,
+    pub char: char,
+    pub password: String,
 }
 
 impl Password {
-    // n1/n2 denote the min/max number of times char can appear in the password
     pub fn is_valid_part_1(&self) -> bool {
-        let char_count = self.password.chars().filter(|c| *c == self.char).count();
-        char_count >= self.n1 && char_count <= self.n2
+        CountRange.check(self).is_ok()
     }
 
-    // n1/n2 denote two positions in the password (1-indexed); char must appear at exactly one of
-    // those two positions.
     pub fn is_valid_part_2(&self) -> bool {
-        let pos_1 = self.password.chars().nth(self.n1 - 1).unwrap() == self.char;
-        let pos_2 = self.password.chars().nth(self.n2 - 1).unwrap() == self.char;
-        pos_1 ^ pos_2
+        ExactlyOnePosition.check(self).is_ok()
+    }
+}
+
+impl FromStr for Password {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected =
+            |part: &str| ParseError::in_line(s, part, "Expected '<n1>-<n2> <char>: <password>'");
+        let number = |part: &str| parse_number(part).map_err(|e| e.within(s, part));
+
+        let (policy, password) = s.split_once(": ").ok_or_else(|| expected(s))?;
+        let (range, char) = policy.split_once(' ').ok_or_else(|| expected(policy))?;
+        let (n1, n2) = range.split_once('-').ok_or_else(|| expected(range))?;
+
+        let mut chars = char.chars();
+        let char = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return Err(ParseError::in_line(s, char, "Expected a single character")),
+        };
+
+        Ok(Password {
+            n1: number(n1)?,
+            n2: number(n2)?,
+            char,
+            password: password.to_string(),
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_password_parsing() {
@@ -40,9 +65,21 @@ mod tests {
                 password: "abcde".to_string(),
             })
         );
+        assert_eq!(
+            "1-x a: abcde".parse::<Password>(),
+            Err(ParseError::new(
+                1,
+                3,
+                "x",
+                "Invalid number: invalid digit found in string"
+            ))
+        );
+        assert_eq!("1-3 ab: abcde".parse::<Password>().unwrap_err().column, 5);
+        assert_eq!("1-3 a abcde".parse::<Password>().unwrap_err().column, 1);
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_password_is_valid_part_1() {
         assert_eq!(
             "1-3 a: abcde"
@@ -68,6 +105,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_password_is_valid_part_2() {
         assert_eq!(
             "1-3 a: abcde"
@@ -91,4 +129,17 @@ mod tests {
             false
         );
     }
+
+    proptest! {
+        #[test]
+        fn test_password_round_trip(
+            n1 in any::<usize>(),
+            n2 in any::<usize>(),
+            char in any::<char>(),
+            password in any::<String>(),
+        ) {
+            let password = Password { n1, n2, char, password };
+            prop_assert_eq!(password.to_string().parse(), Ok(password));
+        }
+    }
 }
@@ -0,0 +1,66 @@
+use crate::data::Password;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+// A password database with size entries. Both policy numbers are always positions within the
+// password, so they make sense under either policy, and the policy's letter is made likely to
+// appear so that plenty of the passwords are valid.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            let length = rng.gen_range(3..=20);
+            let n1 = rng.gen_range(1..length);
+            let n2 = rng.gen_range((n1 + 1)..=length);
+            let char = random_letter(&mut rng);
+            let password = (0..length)
+                .map(|_| {
+                    if rng.gen_bool(0.4) {
+                        char
+                    } else {
+                        random_letter(&mut rng)
+                    }
+                })
+                .collect();
+
+            format!(
+                "{}\n",
+                Password {
+                    n1,
+                    n2,
+                    char,
+                    password
+                }
+            )
+        })
+        .collect()
+}
+
+fn random_letter(rng: &mut StdRng) -> char {
+    (b'a' + rng.gen_range(0..26)) as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let passwords = Day02::parse(&generate(1, 50)).unwrap();
+        assert_eq!(passwords.len(), 50);
+        for password in &passwords {
+            let length = password.password.chars().count();
+            assert!(1 <= password.n1 && password.n1 < password.n2 && password.n2 <= length);
+        }
+        assert!(Day02::part_1(&passwords) > 0);
+        assert!(Day02::part_2(&passwords) > 0);
+    }
+}
@@ -0,0 +1,38 @@
+pub mod data;
+pub mod generator;
+pub mod policy;
+
+use crate::data::Password;
+use aoc_common::{parse_lines, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<Password>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Password>, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_1(passwords: &Vec<Password>) -> usize {
+        part_1(passwords)
+    }
+
+    fn part_2(passwords: &Vec<Password>) -> usize {
+        part_2(passwords)
+    }
+}
+
+// How many passwords are valid?
+pub fn part_1(passwords: &[Password]) -> usize {
+    passwords.iter().filter(|p| p.is_valid_part_1()).count()
+}
+
+// How many passwords are valid (different rules)?
+pub fn part_2(passwords: &[Password]) -> usize {
+    passwords.iter().filter(|p| p.is_valid_part_2()).count()
+}
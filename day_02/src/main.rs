@@ -1,24 +1,41 @@
-mod data;
-
-use crate::data::Password;
+use aoc_common::InputSource;
+use day_02::policy::{parse_policy, report};
+use day_02::Day02;
+use std::process;
 
+// Solves the puzzle as usual, unless given any "--policy <spec>" arguments (see
+// policy::parse_policy), in which case it reports every password that fails those policies
 fn main() {
-    let input = include_str!("input.txt")
-        .trim()
-        .split("\n")
-        .map(|s| s.parse::<Password>().unwrap())
-        .collect();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.iter().any(|arg| arg == "--policy") {
+        aoc_common::main::<Day02>();
+        return;
+    }
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+    if let Err(e) = check_policies(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
 
-// How many passwords are valid?
-fn part_1(passwords: &Vec<Password>) -> usize {
-    passwords.iter().filter(|p| p.is_valid_part_1()).count()
-}
+fn check_policies(args: &[String]) -> Result<(), String> {
+    let mut policies = vec![];
+    let mut input_path = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--policy" {
+            let spec = args.next().ok_or("Missing value for --policy")?;
+            policies.push(parse_policy(spec).map_err(|e| format!("Invalid policy: {}", e))?);
+        } else {
+            input_path = Some(arg.as_str());
+        }
+    }
+
+    let passwords = InputSource::from_arg(input_path)
+        .load::<Day02>()
+        .map_err(|e| e.to_string())?;
+    println!("{}", report(&passwords, &policies));
 
-// How many passwords are valid (different rules)?
-fn part_2(passwords: &Vec<Password>) -> usize {
-    passwords.iter().filter(|p| p.is_valid_part_2()).count()
+    Ok(())
 }
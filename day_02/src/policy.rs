@@ -0,0 +1,499 @@
+use crate::data::Password;
+use aoc_common::{parse_number, ParseError};
+use regex::Regex;
+use std::fmt::{self, Display};
+use unicode_segmentation::UnicodeSegmentation;
+
+// A rule that passwords have to follow
+pub trait PasswordPolicy {
+    fn check(&self, password: &Password) -> Result<(), Failure>;
+}
+
+// Why a password doesn't follow a policy
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Failure {
+    CountOutOfRange {
+        char: char,
+        count: usize,
+        min: usize,
+        max: usize,
+    },
+    BothPositions {
+        char: char,
+        positions: (usize, usize),
+    },
+    NeitherPosition {
+        char: char,
+        positions: (usize, usize),
+    },
+    PositionOutOfRange {
+        position: usize,
+        length: usize,
+    },
+    NoMatch {
+        pattern: String,
+    },
+    ForbiddenSubstring {
+        substring: String,
+    },
+    TooFewDistinctChars {
+        distinct: usize,
+        min: usize,
+    },
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::CountOutOfRange {
+                char,
+                count,
+                min,
+                max,
+            } => write!(
+                f,
+                "'{}' appears {} times, not {} to {}",
+                char, count, min, max
+            ),
+            Failure::BothPositions { char, positions } => write!(
+                f,
+                "'{}' is at both positions {} and {}",
+                char, positions.0, positions.1
+            ),
+            Failure::NeitherPosition { char, positions } => write!(
+                f,
+                "'{}' is at neither position {} nor {}",
+                char, positions.0, positions.1
+            ),
+            Failure::PositionOutOfRange { position, length } => write!(
+                f,
+                "position {} is outside of the password (positions are 1 to {})",
+                position, length
+            ),
+            Failure::NoMatch { pattern } => write!(f, "doesn't match /{}/", pattern),
+            Failure::ForbiddenSubstring { substring } => write!(f, "contains '{}'", substring),
+            Failure::TooFewDistinctChars { distinct, min } => write!(
+                f,
+                "has {} distinct characters, not at least {}",
+                distinct, min
+            ),
+        }
+    }
+}
+
+// Policies count graphemes rather than chars, so that an accented letter made up of a letter plus
+// a combining accent is one character, and doesn't count as that letter
+fn is_char(grapheme: &str, char: char) -> bool {
+    let mut chars = grapheme.chars();
+    chars.next() == Some(char) && chars.next().is_none()
+}
+
+// The password's n1/n2 are the min/max number of times its char can appear (part 1)
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn check(&self, password: &Password) -> Result<(), Failure> {
+        let count = password
+            .password
+            .graphemes(true)
+            .filter(|grapheme| is_char(grapheme, password.char))
+            .count();
+
+        if count >= password.n1 && count <= password.n2 {
+            Ok(())
+        } else {
+            Err(Failure::CountOutOfRange {
+                char: password.char,
+                count,
+                min: password.n1,
+                max: password.n2,
+            })
+        }
+    }
+}
+
+// The password's n1/n2 are two positions in it (1-indexed), and its char must appear at exactly
+// one of them (part 2). Positions count graphemes, like CountRange does.
+pub struct ExactlyOnePosition;
+
+impl PasswordPolicy for ExactlyOnePosition {
+    fn check(&self, password: &Password) -> Result<(), Failure> {
+        let char = password.char;
+        let positions = (password.n1, password.n2);
+        let graphemes: Vec<&str> = password.password.graphemes(true).collect();
+
+        let has_char_at = |position: usize| {
+            let grapheme = position
+                .checked_sub(1)
+                .and_then(|idx| graphemes.get(idx))
+                .ok_or(Failure::PositionOutOfRange {
+                    position,
+                    length: graphemes.len(),
+                })?;
+            Ok(is_char(grapheme, char))
+        };
+
+        match (has_char_at(password.n1)?, has_char_at(password.n2)?) {
+            (true, true) => Err(Failure::BothPositions { char, positions }),
+            (false, false) => Err(Failure::NeitherPosition { char, positions }),
+            _ => Ok(()),
+        }
+    }
+}
+
+// The rest of these ignore the policy written alongside each password
+
+pub struct MatchesRegex(pub Regex);
+
+impl PasswordPolicy for MatchesRegex {
+    fn check(&self, password: &Password) -> Result<(), Failure> {
+        if self.0.is_match(&password.password) {
+            Ok(())
+        } else {
+            Err(Failure::NoMatch {
+                pattern: self.0.as_str().to_string(),
+            })
+        }
+    }
+}
+
+pub struct ForbiddenSubstrings(pub Vec<String>);
+
+impl PasswordPolicy for ForbiddenSubstrings {
+    fn check(&self, password: &Password) -> Result<(), Failure> {
+        match self
+            .0
+            .iter()
+            .find(|s| password.password.contains(s.as_str()))
+        {
+            Some(substring) => Err(Failure::ForbiddenSubstring {
+                substring: substring.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct MinDistinctChars(pub usize);
+
+impl PasswordPolicy for MinDistinctChars {
+    fn check(&self, password: &Password) -> Result<(), Failure> {
+        let mut graphemes: Vec<&str> = password.password.graphemes(true).collect();
+        graphemes.sort_unstable();
+        graphemes.dedup();
+
+        if graphemes.len() >= self.0 {
+            Ok(())
+        } else {
+            Err(Failure::TooFewDistinctChars {
+                distinct: graphemes.len(),
+                min: self.0,
+            })
+        }
+    }
+}
+
+// Picks a policy by name, along with its setting for the ones that need it: "count", "positions",
+// "regex:<pattern>", "forbid:<substring>,<substring>,..." or "distinct:<min>"
+pub fn parse_policy(spec: &str) -> Result<Box<dyn PasswordPolicy>, ParseError> {
+    let (name, setting) = match spec.split_once(':') {
+        Some((name, setting)) => (name, Some(setting)),
+        None => (spec, None),
+    };
+    let setting = || {
+        let reason = format!("Expected '{}:<...>'", name);
+        setting.ok_or_else(|| ParseError::in_line(spec, spec, reason))
+    };
+
+    match name {
+        "count" => Ok(Box::new(CountRange)),
+        "positions" => Ok(Box::new(ExactlyOnePosition)),
+        "regex" => {
+            let pattern = setting()?;
+            let regex = Regex::new(pattern).map_err(|e| {
+                let reason = format!("Invalid regex: {}", e);
+                ParseError::in_line(spec, pattern, reason)
+            })?;
+            Ok(Box::new(MatchesRegex(regex)))
+        }
+        "forbid" => {
+            // An empty substring is in every password, so it's almost certainly a stray comma
+            let substrings = setting()?
+                .split(',')
+                .map(|substring| match substring {
+                    "" => Err(ParseError::in_line(spec, substring, "Expected a substring")),
+                    _ => Ok(substring.to_string()),
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Box::new(ForbiddenSubstrings(substrings)))
+        }
+        "distinct" => {
+            let min = setting()?;
+            Ok(Box::new(MinDistinctChars(
+                parse_number(min).map_err(|e| e.within(spec, min))?,
+            )))
+        }
+        _ => Err(ParseError::in_line(spec, name, "Unknown policy")),
+    }
+}
+
+// A password that doesn't follow one or more policies, and the line it's on
+#[derive(Eq, PartialEq, Debug)]
+pub struct FailedPassword<'a> {
+    pub line: usize,
+    pub password: &'a Password,
+    pub failures: Vec<Failure>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub struct Report<'a> {
+    pub checked: usize,
+    pub failed: Vec<FailedPassword<'a>>,
+}
+
+// Checks every password against every policy
+pub fn report<'a>(passwords: &'a [Password], policies: &[Box<dyn PasswordPolicy>]) -> Report<'a> {
+    let failed = passwords
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, password)| {
+            let failures: Vec<Failure> = policies
+                .iter()
+                .filter_map(|policy| policy.check(password).err())
+                .collect();
+
+            if failures.is_empty() {
+                None
+            } else {
+                Some(FailedPassword {
+                    line: idx + 1,
+                    password,
+                    failures,
+                })
+            }
+        })
+        .collect();
+
+    Report {
+        checked: passwords.len(),
+        failed,
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for failed in &self.failed {
+            writeln!(f, "line {}: {}", failed.line, failed.password)?;
+            for failure in &failed.failures {
+                writeln!(f, "    {}", failure)?;
+            }
+        }
+
+        write!(
+            f,
+            "{} of {} passwords failed",
+            self.failed.len(),
+            self.checked
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn password(s: &str) -> Password {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_count_range() {
+        assert_eq!(CountRange.check(&password("1-3 a: abcde")), Ok(()));
+        assert_eq!(
+            CountRange.check(&password("1-3 b: cdefg")),
+            Err(Failure::CountOutOfRange {
+                char: 'b',
+                count: 0,
+                min: 1,
+                max: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_policies_agree_on_graphemes() {
+        // "e\u{301}" is an e with a combining acute accent, which isn't an e to either policy
+        let pw = password("1-1 e: e\u{301}ae");
+        assert_eq!(CountRange.check(&pw), Ok(()));
+        assert_eq!(
+            ExactlyOnePosition.check(&password("1-3 e: e\u{301}ae")),
+            Ok(())
+        );
+        assert_eq!(
+            CountRange.check(&password("2-3 e: e\u{301}ae")),
+            Err(Failure::CountOutOfRange {
+                char: 'e',
+                count: 1,
+                min: 2,
+                max: 3
+            })
+        );
+        assert_eq!(
+            MinDistinctChars(4).check(&pw).unwrap_err().to_string(),
+            "has 3 distinct characters, not at least 4"
+        );
+    }
+
+    #[test]
+    fn test_exactly_one_position() {
+        assert_eq!(ExactlyOnePosition.check(&password("1-3 a: abcde")), Ok(()));
+        assert_eq!(
+            ExactlyOnePosition.check(&password("1-3 b: cdefg")),
+            Err(Failure::NeitherPosition {
+                char: 'b',
+                positions: (1, 3)
+            })
+        );
+        assert_eq!(
+            ExactlyOnePosition.check(&password("2-9 c: ccccccccc")),
+            Err(Failure::BothPositions {
+                char: 'c',
+                positions: (2, 9)
+            })
+        );
+    }
+
+    #[test]
+    fn test_exactly_one_position_out_of_range() {
+        assert_eq!(
+            ExactlyOnePosition.check(&password("0-3 a: abcde")),
+            Err(Failure::PositionOutOfRange {
+                position: 0,
+                length: 5
+            })
+        );
+        assert_eq!(
+            ExactlyOnePosition.check(&password("1-6 a: abcde")),
+            Err(Failure::PositionOutOfRange {
+                position: 6,
+                length: 5
+            })
+        );
+        assert_eq!(
+            ExactlyOnePosition
+                .check(&password("1-2 a: "))
+                .unwrap_err()
+                .to_string(),
+            "position 1 is outside of the password (positions are 1 to 0)"
+        );
+        assert!(!password("3-9 c: abc").is_valid_part_2());
+    }
+
+    #[test]
+    fn test_exactly_one_position_graphemes() {
+        // "e\u{301}" is an e with a combining acute accent: two chars, but one grapheme
+        let pw = password("3-4 x: e\u{301}ax\u{301}x");
+        assert_eq!(pw.password.chars().count(), 6);
+        assert_eq!(ExactlyOnePosition.check(&pw), Ok(()));
+
+        let pw = password("1-2 e: e\u{301}e");
+        assert_eq!(ExactlyOnePosition.check(&pw), Ok(()));
+
+        let pw = password("2-3 é: 👩‍👩‍👧é!");
+        assert_eq!(ExactlyOnePosition.check(&pw), Ok(()));
+        assert_eq!(
+            ExactlyOnePosition.check(&password("2-4 é: 👩‍👩‍👧é!")),
+            Err(Failure::PositionOutOfRange {
+                position: 4,
+                length: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_other_policies() {
+        let pw = password("1-3 a: abcabc");
+
+        assert_eq!(
+            MatchesRegex(Regex::new("^[a-c]+$").unwrap()).check(&pw),
+            Ok(())
+        );
+        assert_eq!(
+            MatchesRegex(Regex::new("[0-9]").unwrap())
+                .check(&pw)
+                .unwrap_err()
+                .to_string(),
+            "doesn't match /[0-9]/"
+        );
+        assert_eq!(
+            ForbiddenSubstrings(vec!["xyz".to_string(), "ca".to_string()]).check(&pw),
+            Err(Failure::ForbiddenSubstring {
+                substring: "ca".to_string()
+            })
+        );
+        assert_eq!(MinDistinctChars(3).check(&pw), Ok(()));
+        assert_eq!(
+            MinDistinctChars(4).check(&pw),
+            Err(Failure::TooFewDistinctChars {
+                distinct: 3,
+                min: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        let pw = password("1-3 b: cdefg");
+        let check = |spec: &str| parse_policy(spec).unwrap().check(&pw).is_ok();
+
+        assert!(!check("count"));
+        assert!(!check("positions"));
+        assert!(check("regex:^[c-g]{5}$"));
+        assert!(check("forbid:abc,xyz"));
+        assert!(!check("forbid:abc,def"));
+        assert!(check("distinct:5"));
+        assert!(!check("distinct:6"));
+
+        let error = |spec: &str| parse_policy(spec).err().unwrap();
+        assert_eq!(
+            error("length"),
+            ParseError::new(1, 1, "length", "Unknown policy")
+        );
+        assert_eq!(error("regex").reason, "Expected 'regex:<...>'");
+        assert_eq!(error("regex:[a-").column, 7);
+        assert_eq!(error("distinct:x").column, 10);
+        assert_eq!(
+            error("forbid:"),
+            ParseError::new(1, 8, "", "Expected a substring")
+        );
+        assert_eq!(
+            error("forbid:a,,b"),
+            ParseError::new(1, 10, "", "Expected a substring")
+        );
+        assert_eq!(error("forbid:a,").column, 10);
+    }
+
+    #[test]
+    fn test_report() {
+        let passwords: Vec<Password> = ["1-3 a: abcde", "1-3 b: cdefg", "2-9 c: ccccccccc"]
+            .iter()
+            .map(|s| password(s))
+            .collect();
+        let policies = [
+            parse_policy("count").unwrap(),
+            parse_policy("distinct:2").unwrap(),
+        ];
+
+        let report = report(&passwords, &policies);
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.failed.len(), 2);
+        assert_eq!(
+            report.to_string(),
+            "line 2: 1-3 b: cdefg\n    \
+                 'b' appears 0 times, not 1 to 3\n\
+             line 3: 2-9 c: ccccccccc\n    \
+                 has 1 distinct characters, not at least 2\n\
+             2 of 3 passwords failed"
+        );
+    }
+}
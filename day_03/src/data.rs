@@ -1,3 +1,6 @@
+use aoc_common::grid::Coord;
+use aoc_common::{Grid, ParseError};
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Debug)]
@@ -6,69 +9,73 @@ pub enum Node {
     Open,
 }
 
-pub type Row = Vec<Node>;
-pub type Rows = Vec<Row>;
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Node::Tree => write!(f, "#"),
+            Node::Open => write!(f, "."),
+        }
+    }
+}
 
 pub struct Map {
-    pub rows: Rows,
+    pub grid: Grid<Node>,
 }
 
 impl Map {
     pub fn count_trees(&self, dx: usize, dy: usize) -> usize {
-        let mut tree_count = 0;
-        let mut x = 0;
-        let mut y = 0;
-
-        while y < self.rows.len() {
-            if self.node_at(x, y) == &Node::Tree {
-                tree_count += 1;
-            }
-
-            x += dx;
-            y += dy;
-        }
+        self.route((0, 0), (dx as isize, dy as isize))
+            .filter(|(_coord, node)| **node == Node::Tree)
+            .count()
+    }
 
-        tree_count
+    // Every spot visited heading from start (inclusive) along the (dx, dy) slope, until going off
+    // the bottom (or top) of the map. The map repeats in the x-direction, so going off either side
+    // wraps around, and each coord's x is on the map itself. With a dy of 0 this goes on forever.
+    pub fn route(&self, (x, y): Coord, (dx, dy): Coord) -> impl Iterator<Item = (Coord, &Node)> {
+        let width = self.grid.width() as isize;
+        let height = self.grid.height() as isize;
+
+        (0..)
+            .map(move |step| (x + dx * step, y + dy * step))
+            .take_while(move |&(_x, y)| y >= 0 && y < height)
+            .map(move |(x, y)| ((x.rem_euclid(width), y), self.grid.get_wrapping((x, y))))
     }
 
-    // Account for repeating in the x-direction by taking (x % width)
-    fn node_at(&self, x: usize, y: usize) -> &Node {
-        let row = &self.rows[y];
-        let x_idx = x % row.len();
-        &row[x_idx]
+    // The map repeats in the x-direction
+    pub fn node_at(&self, x: usize, y: usize) -> &Node {
+        self.grid.get_wrapping((x as isize, y as isize))
     }
 }
 
 impl FromStr for Map {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut rows = Rows::new();
-
-        for line in s.split("\n") {
-            let mut row = Row::new();
-            for char in line.chars() {
-                let node = match char {
-                    '.' => Node::Open,
-                    '#' => Node::Tree,
-                    _ => panic!("Unknown character: '{}'", char),
-                };
-                row.push(node);
-            }
-            rows.push(row);
-        }
+        let grid = Grid::parse(s, |char| match char {
+            '.' => Some(Node::Open),
+            '#' => Some(Node::Tree),
+            _ => None,
+        })?;
+
+        Ok(Map { grid })
+    }
+}
 
-        Ok(Map { rows })
+impl Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     // 10
     // x=12 => r1
-    fn example() -> Map {
+    pub fn example() -> Map {
         "
         ..##.......
         #...#...#..
@@ -90,9 +97,11 @@ mod tests {
     #[test]
     fn test_map_from_str() {
         let map = example();
-        assert_eq!(map.rows[0][0], Node::Open);
-        assert_eq!(map.rows[0][1], Node::Open);
-        assert_eq!(map.rows[0][2], Node::Tree);
+        assert_eq!(map.node_at(0, 0), &Node::Open);
+        assert_eq!(map.node_at(1, 0), &Node::Open);
+        assert_eq!(map.node_at(2, 0), &Node::Tree);
+        assert_eq!(map.node_at(13, 0), &Node::Tree);
+        assert!("..#\n.x.".parse::<Map>().is_err());
     }
 
     #[test]
@@ -104,4 +113,47 @@ mod tests {
         assert_eq!(map.count_trees(7, 1), 4);
         assert_eq!(map.count_trees(1, 2), 2);
     }
+
+    #[test]
+    fn test_map_route() {
+        let map = example();
+        let route: Vec<_> = map.route((0, 0), (3, 1)).collect();
+        assert_eq!(route.len(), 11);
+        assert_eq!(route[0], ((0, 0), &Node::Open));
+        assert_eq!(route[1], ((3, 1), &Node::Open));
+        assert_eq!(route[4], ((1, 4), &Node::Tree));
+        assert_eq!(route[10], ((8, 10), &Node::Tree));
+
+        let coords = |start, slope| -> Vec<Coord> {
+            map.route(start, slope)
+                .map(|(coord, _node)| coord)
+                .collect()
+        };
+        assert_eq!(
+            coords((1, 0), (-1, 2)),
+            vec![(1, 0), (0, 2), (10, 4), (9, 6), (8, 8), (7, 10)]
+        );
+        assert_eq!(coords((25, 8), (0, 1)), vec![(3, 8), (3, 9), (3, 10)]);
+        assert_eq!(coords((4, 2), (1, -1)), vec![(4, 2), (5, 1), (6, 0)]);
+        assert_eq!(coords((0, 11), (1, 1)), vec![]);
+        assert_eq!(coords((0, -1), (1, 1)), vec![]);
+        assert_eq!(map.route((0, 0), (1, 0)).take(20).count(), 20);
+
+        let trees = map
+            .route((0, 0), (-3, 1))
+            .filter(|(_coord, node)| **node == Node::Tree)
+            .count();
+        assert_eq!(trees, 3);
+    }
+
+    proptest! {
+        #[test]
+        fn test_map_round_trip(rows in (1..40_usize).prop_flat_map(|width| {
+            let row = prop::string::string_regex(&format!("[.#]{{{}}}", width)).unwrap();
+            prop::collection::vec(row, 1..40)
+        })) {
+            let s = rows.join("\n");
+            prop_assert_eq!(s.parse::<Map>().unwrap().to_string(), s);
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use crate::data::{Map, Node};
+use aoc_common::Grid;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 323;
+
+const WIDTH: usize = 31;
+
+// A map that's 31 squares wide and size squares tall, where about a quarter of the squares are
+// trees
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let nodes = (0..(WIDTH * size))
+        .map(|_| {
+            if rng.gen_bool(0.25) {
+                Node::Tree
+            } else {
+                Node::Open
+            }
+        })
+        .collect();
+
+    format!(
+        "{}\n",
+        Map {
+            grid: Grid::new(WIDTH, size, nodes)
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let map = Day03::parse(&generate(1, 50)).unwrap();
+        assert_eq!((map.grid.width(), map.grid.height()), (31, 50));
+        let trees = map.grid.iter().filter(|(_, node)| **node == Node::Tree).count();
+        assert!((300..=475).contains(&trees), "{} of 1550 squares are trees", trees);
+        assert!(Day03::part_1(&map) > 0);
+        Day03::part_2(&map);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::data::Map;
+use aoc_common::{ParseError, Solution};
+
+pub mod data;
+pub mod generator;
+pub mod render;
+pub mod slopes;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Map;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        input.trim_end().parse()
+    }
+
+    fn part_1(map: &Map) -> usize {
+        part_1(map)
+    }
+
+    fn part_2(map: &Map) -> usize {
+        part_2(map)
+    }
+}
+
+// Starting from top-left along a slope of right 3 / down 1, how many trees would you encounter?
+pub fn part_1(map: &Map) -> usize {
+    map.count_trees(3, 1)
+}
+
+// For each of 5 slopes, find number of trees, then multiply all results together
+pub fn part_2(map: &Map) -> usize {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|(dx, dy)| map.count_trees(*dx, *dy))
+        .reduce(|total, this| total * this)
+        .unwrap()
+}
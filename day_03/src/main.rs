@@ -1,23 +1,78 @@
-use crate::data::Map;
+use aoc_common::grid::Coord;
+use aoc_common::{parse_number, InputSource};
+use day_03::render::{overlay, render_text, write_png, write_ppm, Colour};
+use day_03::Day03;
+use std::fs::File;
+use std::io::BufWriter;
+use std::process;
 
-mod data;
+// How many pixels wide and tall each spot on the map is in images
+const IMAGE_SCALE: usize = 4;
 
+// Solves the puzzle as usual, unless given "--route <dx>,<dy>", in which case it draws the map with
+// that route over it (in colour with --colour, or as a .png or .ppm image with --image <path>)
 fn main() {
-    let map = include_str!("input.txt").trim().parse().unwrap();
-    println!("part_1: {}", part_1(&map));
-    println!("part_2: {}", part_2(&map));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.iter().any(|arg| arg == "--route") {
+        aoc_common::main::<Day03>();
+        return;
+    }
+
+    if let Err(e) = draw_route(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
 
-// Starting from top-left along a slope of right 3 / down 1, how many trees would you encounter?
-fn part_1(map: &Map) -> usize {
-    map.count_trees(3, 1)
+fn draw_route(args: &[String]) -> Result<(), String> {
+    let mut slope = None;
+    let mut colour = Colour::Plain;
+    let mut image_path = None;
+    let mut input_path = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--route" => {
+                let value = args
+                    .next()
+                    .filter(|value| !value.starts_with("--"))
+                    .ok_or("Missing value for --route")?;
+                slope = Some(parse_slope(value)?);
+            }
+            "--colour" => colour = Colour::Ansi,
+            "--image" => image_path = Some(args.next().ok_or("Missing value for --image")?),
+            _ => input_path = Some(arg.as_str()),
+        }
+    }
+
+    let map = InputSource::from_arg(input_path)
+        .load::<Day03>()
+        .map_err(|e| e.to_string())?;
+    let spots = overlay(&map, (0, 0), slope.ok_or("Missing value for --route")?);
+
+    match image_path {
+        None => println!("{}", render_text(&spots, colour)),
+        Some(path) => {
+            let file = BufWriter::new(File::create(path).map_err(|e| e.to_string())?);
+            let result = if path.ends_with(".ppm") {
+                write_ppm(&spots, IMAGE_SCALE, file)
+            } else {
+                write_png(&spots, IMAGE_SCALE, file)
+            };
+            result.map_err(|e| format!("Couldn't write {}: {}", path, e))?;
+        }
+    }
+
+    Ok(())
 }
 
-// For each of 5 slopes, find number of trees, then multiply all results together
-fn part_2(map: &Map) -> usize {
-    vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
-        .iter()
-        .map(|(dx, dy)| map.count_trees(*dx, *dy))
-        .reduce(|total, this| total * this)
-        .unwrap()
+fn parse_slope(s: &str) -> Result<Coord, String> {
+    let invalid = || format!("Invalid --route (expected '<dx>,<dy>'): {}", s);
+    let (dx, dy) = s.split_once(',').ok_or_else(invalid)?;
+
+    match (parse_number(dx), parse_number(dy)) {
+        (Ok(dx), Ok(dy)) if dy != 0 => Ok((dx, dy)),
+        _ => Err(invalid()),
+    }
 }
@@ -0,0 +1,162 @@
+use crate::data::{Map, Node};
+use aoc_common::grid::Coord;
+use aoc_common::Grid;
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+
+// A spot on the map, with a route drawn over it
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Spot {
+    Open,
+    Tree,
+    Miss,
+    Hit,
+}
+
+impl Display for Spot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Spot::Open => write!(f, "."),
+            Spot::Tree => write!(f, "#"),
+            Spot::Miss => write!(f, "O"),
+            Spot::Hit => write!(f, "X"),
+        }
+    }
+}
+
+impl Spot {
+    fn ansi_colour(self) -> &'static str {
+        match self {
+            Spot::Open => "2",
+            Spot::Tree => "32",
+            Spot::Miss => "1;36",
+            Spot::Hit => "1;31",
+        }
+    }
+
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Spot::Open => [240, 240, 240],
+            Spot::Tree => [34, 139, 34],
+            Spot::Miss => [30, 144, 255],
+            Spot::Hit => [220, 20, 60],
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Colour {
+    Plain,
+    Ansi,
+}
+
+// The map with the route from start along the slope marked on it, like the puzzle text does: an O
+// where the route misses a tree and an X where it hits one. The map repeats in the x-direction, so
+// a route that wraps around is drawn over the same copy of it.
+pub fn overlay(map: &Map, start: Coord, slope: Coord) -> Grid<Spot> {
+    let mut spots = map.grid.map(|_coord, node| match node {
+        Node::Open => Spot::Open,
+        Node::Tree => Spot::Tree,
+    });
+
+    for (coord, node) in map.route(start, slope) {
+        let spot = match node {
+            Node::Open => Spot::Miss,
+            Node::Tree => Spot::Hit,
+        };
+        spots.set(coord, spot);
+    }
+
+    spots
+}
+
+pub fn render_text(spots: &Grid<Spot>, colour: Colour) -> String {
+    match colour {
+        Colour::Plain => spots.to_string(),
+        Colour::Ansi => spots
+            .map(|_coord, spot| format!("\x1b[{}m{}\x1b[0m", spot.ansi_colour(), spot))
+            .to_string(),
+    }
+}
+
+// A binary PPM image, with each spot drawn as a square of scale by scale pixels
+pub fn write_ppm<W: Write>(spots: &Grid<Spot>, scale: usize, mut writer: W) -> io::Result<()> {
+    let (width, height) = (spots.width() * scale, spots.height() * scale);
+    write!(writer, "P6\n{} {}\n255\n", width, height)?;
+    writer.write_all(&pixels(spots, scale))
+}
+
+// Same, as a PNG
+pub fn write_png<W: Write>(spots: &Grid<Spot>, scale: usize, writer: W) -> io::Result<()> {
+    let (width, height) = (spots.width() * scale, spots.height() * scale);
+    let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels(spots, scale)))
+        .map_err(io::Error::other)
+}
+
+// RGB values, row by row
+fn pixels(spots: &Grid<Spot>, scale: usize) -> Vec<u8> {
+    let width = spots.width() * scale;
+    let mut pixels = Vec::with_capacity(width * spots.height() * scale * 3);
+
+    for y in 0..(spots.height() * scale) {
+        for x in 0..width {
+            let coord = ((x / scale) as isize, (y / scale) as isize);
+            pixels.extend(spots.get(coord).unwrap().rgb());
+        }
+    }
+
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::tests::example;
+
+    #[test]
+    fn test_render_text() {
+        let spots = overlay(&example(), (0, 0), (3, 1));
+        let text = render_text(&spots, Colour::Plain);
+        assert_eq!(text.lines().next(), Some("O.##......."));
+        assert_eq!(text.lines().nth(1), Some("#..O#...#.."));
+        assert_eq!(text.lines().nth(4), Some(".X...##..#."));
+        assert_eq!(text.matches('X').count(), 7);
+        assert_eq!(text.matches('O').count(), 4);
+
+        let text = render_text(&spots, Colour::Ansi);
+        assert!(text.starts_with("\x1b[1;36mO\x1b[0m\x1b[2m.\x1b[0m\x1b[32m#\x1b[0m"));
+        assert_eq!(text.lines().count(), 11);
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let spots = Grid::new(2, 1, vec![Spot::Tree, Spot::Hit]);
+        let mut ppm = vec![];
+        write_ppm(&spots, 2, &mut ppm).unwrap();
+
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 4 * 2 * 3);
+        assert_eq!(&ppm[header.len()..][..6], &[34, 139, 34, 34, 139, 34]);
+        assert_eq!(&ppm[(ppm.len() - 3)..], &[220, 20, 60]);
+    }
+
+    #[test]
+    fn test_write_png() {
+        let spots = overlay(&example(), (0, 0), (1, 2));
+        let mut png = vec![];
+        write_png(&spots, 3, &mut png).unwrap();
+
+        let mut reader = png::Decoder::new(&png[..]).read_info().unwrap();
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).unwrap();
+        assert_eq!((info.width, info.height), (33, 33));
+        assert_eq!(&buffer[..info.buffer_size()], &pixels(&spots, 3)[..]);
+    }
+}
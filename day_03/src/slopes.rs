@@ -0,0 +1,120 @@
+use crate::data::{Map, Node};
+use aoc_common::grid::Coord;
+use rayon::prelude::*;
+use std::ops::RangeInclusive;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Execution {
+    Sequential,
+    Parallel,
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct SlopeTrees {
+    pub slope: Coord,
+    pub trees: usize,
+}
+
+// Slopes ordered from the fewest trees to the most, with ties ordered by slope
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct SlopeRanking {
+    pub slopes: Vec<SlopeTrees>,
+}
+
+impl SlopeRanking {
+    // The safest slopes: every one that's tied for the fewest trees
+    pub fn fewest_trees(&self) -> &[SlopeTrees] {
+        match self.slopes.first() {
+            Some(first) => {
+                let tied = self.slopes.iter().take_while(|s| s.trees == first.trees);
+                &self.slopes[..tied.count()]
+            }
+            None => &[],
+        }
+    }
+
+    pub fn most_trees(&self) -> &[SlopeTrees] {
+        match self.slopes.last() {
+            Some(last) => {
+                let tied = self
+                    .slopes
+                    .iter()
+                    .rev()
+                    .take_while(|s| s.trees == last.trees);
+                &self.slopes[(self.slopes.len() - tied.count())..]
+            }
+            None => &[],
+        }
+    }
+}
+
+impl Map {
+    // Counts the trees on every slope from the top-left with dx and dy in the given ranges. Routes
+    // that don't head down the map never reach the bottom, so any dy below 1 is skipped.
+    pub fn rank_slopes(
+        &self,
+        dxs: RangeInclusive<isize>,
+        dys: RangeInclusive<isize>,
+        execution: Execution,
+    ) -> SlopeRanking {
+        let slopes: Vec<Coord> = dys
+            .filter(|&dy| dy >= 1)
+            .flat_map(|dy| dxs.clone().map(move |dx| (dx, dy)))
+            .collect();
+        let count = |&slope: &Coord| SlopeTrees {
+            slope,
+            trees: self
+                .route((0, 0), slope)
+                .filter(|(_coord, node)| **node == Node::Tree)
+                .count(),
+        };
+
+        let mut slopes: Vec<SlopeTrees> = match execution {
+            Execution::Sequential => slopes.iter().map(count).collect(),
+            Execution::Parallel => slopes.par_iter().map(count).collect(),
+        };
+        slopes.sort_by_key(|s| (s.trees, s.slope));
+
+        SlopeRanking { slopes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::tests::example;
+
+    #[test]
+    fn test_rank_slopes() {
+        let ranking = example().rank_slopes(1..=7, 1..=2, Execution::Sequential);
+        assert_eq!(ranking.slopes.len(), 14);
+        assert_eq!(
+            ranking.fewest_trees(),
+            &[SlopeTrees {
+                slope: (5, 2),
+                trees: 0
+            }]
+        );
+        assert_eq!(
+            ranking.most_trees(),
+            &[SlopeTrees {
+                slope: (3, 1),
+                trees: 7
+            }]
+        );
+
+        let ranking = example().rank_slopes(-2..=2, -1..=0, Execution::Sequential);
+        assert_eq!(ranking.slopes, vec![]);
+        assert_eq!(ranking.fewest_trees(), &[]);
+        assert_eq!(ranking.most_trees(), &[]);
+    }
+
+    #[test]
+    fn test_rank_slopes_parallel() {
+        let map = example();
+        assert_eq!(
+            map.rank_slopes(-11..=11, 1..=11, Execution::Parallel),
+            map.rank_slopes(-11..=11, 1..=11, Execution::Sequential)
+        );
+    }
+}
@@ -1,124 +1,92 @@
+use crate::schema::Schema;
+use crate::validation::Violation;
+use aoc_common::ParseError;
 use lazy_static::lazy_static;
-use regex::Regex;
 use std::collections::HashMap;
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
-#[derive(Debug)]
+lazy_static! {
+    // The puzzle's rules for each field
+    pub static ref PASSPORT_SCHEMA: Schema =
+        Schema::from_toml(include_str!("passport.toml")).unwrap();
+}
+
+#[derive(Eq, PartialEq, Debug)]
 pub struct Passport(HashMap<String, String>);
 
 impl Passport {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
     // All 8 fields must be present, OR "cid" may be missing
     pub fn is_valid_part_1(&self) -> bool {
-        let mut keys = self.0.keys();
-        let count = keys.len();
-        let cid_missing = keys.find(|k| k == &&"cid".to_string()).is_none();
-
-        if count == 8 {
-            true
-        } else if count == 7 && cid_missing {
-            true
-        } else {
-            false
-        }
+        PASSPORT_SCHEMA.has_required_fields(self)
     }
 
-    // Previous rules, plus some additional validations
+    // Previous rules, plus each field's value has to follow its rule
     pub fn is_valid_part_2(&self) -> bool {
-        let mut keys = self.0.keys();
-        let count = keys.len();
-        let cid_missing = keys.find(|k| k == &&"cid".to_string()).is_none();
-
-        if count == 8 || count == 7 && cid_missing {
-            // byr (Birth Year) - four digits; at least 1920 and at most 2002.
-            let byr = self.0.get("byr").unwrap().parse::<usize>().unwrap();
-            if !(1920..=2002).contains(&byr) {
-                return false;
-            }
-
-            // iyr (Issue Year) - four digits; at least 2010 and at most 2020.
-            let iyr = self.0.get("iyr").unwrap().parse::<usize>().unwrap();
-            if !(2010..=2020).contains(&iyr) {
-                return false;
-            }
+        PASSPORT_SCHEMA.is_valid(self)
+    }
 
-            // eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
-            let eyr = self.0.get("eyr").unwrap().parse::<usize>().unwrap();
-            if !(2020..=2030).contains(&eyr) {
-                return false;
-            }
+    // Everything wrong with the passport under the puzzle's rules
+    pub fn validate(&self) -> Vec<Violation> {
+        PASSPORT_SCHEMA.validate(self)
+    }
+}
 
-            // hgt (Height) - a number followed by either cm or in:
-            //   - If cm, the number must be at least 150 and at most 193.
-            //   - If in, the number must be at least 59 and at most 76.
-            lazy_static! {
-                static ref HGT_REGEX: Regex = Regex::new(r"\A(\d+)(cm|in)\z").unwrap();
-            }
-            let hgt = self.0.get("hgt").unwrap();
-            if let Some(caps) = HGT_REGEX.captures(hgt) {
-                let num = caps.get(1).unwrap().as_str().parse::<usize>().unwrap();
-                match caps.get(2).unwrap().as_str() {
-                    "cm" => {
-                        if !(150..=193).contains(&num) {
-                            return false;
-                        }
-                    }
-                    "in" => {
-                        if !(59..=76).contains(&num) {
-                            return false;
-                        }
-                    }
-                    _ => panic!(),
-                }
-            } else {
-                return false;
-            }
+// A Passport is a whitespace-separated list of "key:value" fields, which may span multiple lines
+impl FromStr for Passport {
+    type Err = ParseError;
 
-            // hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
-            lazy_static! {
-                static ref HCL_REGEX: Regex = Regex::new(r"\A#[0-9a-f]{6}\z").unwrap();
-            }
-            let hcl = self.0.get("hcl").unwrap();
-            if !HCL_REGEX.is_match(hcl) {
-                return false;
-            }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut passport = HashMap::new();
 
-            // ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
-            lazy_static! {
-                static ref ECL_REGEX: Regex =
-                    Regex::new(r"\A(amb|blu|brn|gry|grn|hzl|oth)\z").unwrap();
-            }
-            let ecl = self.0.get("ecl").unwrap();
-            if !ECL_REGEX.is_match(ecl) {
-                return false;
+        for (idx, line) in s.lines().enumerate() {
+            for info in line.split_whitespace() {
+                let (key, value) = info.split_once(':').ok_or_else(|| {
+                    ParseError::in_line(line, info, "Expected '<key>:<value>'").offset_lines(idx)
+                })?;
+                passport.insert(key.into(), value.into());
             }
+        }
 
-            // pid (Passport ID) - a nine-digit number, including leading zeroes.
-            lazy_static! {
-                static ref PID_REGEX: Regex = Regex::new(r"\A\d{9}\z").unwrap();
-            }
-            let pid = self.0.get("pid").unwrap();
-            if !PID_REGEX.is_match(pid) {
-                return false;
-            }
+        Ok(Passport(passport))
+    }
+}
 
-            true
-        } else {
-            false
-        }
+// The fields on one line, sorted by key so the same passport is always written the same way
+impl Display for Passport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut fields: Vec<_> = self.0.iter().collect();
+        fields.sort();
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|(key, value)| format!("{}:{}", key, value))
+            .collect();
+        write!(f, "{}", fields.join(" "))
     }
 }
 
-impl FromStr for Passport {
-    type Err = ();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut passport = HashMap::new();
+    #[test]
+    fn test_passport_display() {
+        let passport: Passport = "iyr:2013 ecl:amb\nhcl:#cfa07d".parse().unwrap();
+        assert_eq!(passport.to_string(), "ecl:amb hcl:#cfa07d iyr:2013");
+    }
 
-        for info in s.split(' ') {
-            let kv: Vec<&str> = info.split(':').take(2).collect();
-            passport.insert(kv[0].into(), kv[1].into());
+    proptest! {
+        #[test]
+        fn test_passport_round_trip(
+            fields in prop::collection::hash_map("[a-z]{1,4}", "[!-~]{0,10}", 0..10),
+        ) {
+            let passport = Passport(fields);
+            prop_assert_eq!(passport.to_string().parse(), Ok(passport));
         }
-
-        Ok(Passport(passport))
     }
 }
@@ -0,0 +1,94 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 250;
+
+const FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+// A batch of size passports. Fields are occasionally missing and values are occasionally invalid,
+// so that every validation rule gets exercised. Years are always numbers, since part 2 relies on
+// that.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| passport(&mut rng))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+// The fields come in a random order, and are split across a random number of lines
+fn passport(rng: &mut StdRng) -> String {
+    let mut fields = vec![];
+    for field in FIELDS.iter() {
+        if rng.gen_bool(0.95) {
+            let valid = rng.gen_bool(0.9);
+            fields.push(format!("{}:{}", field, value(rng, field, valid)));
+        }
+    }
+    fields.shuffle(rng);
+
+    let mut passport = String::new();
+    for (idx, field) in fields.iter().enumerate() {
+        if idx > 0 {
+            passport.push(if rng.gen_bool(0.2) { '\n' } else { ' ' });
+        }
+        passport.push_str(field);
+    }
+
+    passport
+}
+
+fn value(rng: &mut StdRng, field: &str, valid: bool) -> String {
+    match (field, valid) {
+        ("byr", true) => rng.gen_range(1920..=2002).to_string(),
+        ("byr", false) => rng.gen_range(2003..=2030).to_string(),
+        ("iyr", true) => rng.gen_range(2010..=2020).to_string(),
+        ("iyr", false) => rng.gen_range(1990..2010).to_string(),
+        ("eyr", true) => rng.gen_range(2020..=2030).to_string(),
+        ("eyr", false) => rng.gen_range(2031..=2040).to_string(),
+        ("hgt", true) if rng.gen_bool(0.5) => format!("{}cm", rng.gen_range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.gen_range(59..=76)),
+        ("hgt", false) if rng.gen_bool(0.5) => format!("{}cm", rng.gen_range(100..150)),
+        ("hgt", false) => rng.gen_range(59..=193).to_string(),
+        ("hcl", true) => format!("#{:06x}", rng.gen_range(0..0x1000000)),
+        ("hcl", false) => format!("{:06x}", rng.gen_range(0..0x1000000)),
+        ("ecl", true) => EYE_COLORS.choose(rng).unwrap().to_string(),
+        ("ecl", false) => ["zzz", "gmt", "xry"].choose(rng).unwrap().to_string(),
+        ("pid", true) => format!("{:09}", rng.gen_range(0..1_000_000_000)),
+        ("pid", false) => format!("{:010}", rng.gen_range(0..10_000_000_000u64)),
+        _ => rng.gen_range(100..=350).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Violation;
+    use crate::Day04;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let passports = Day04::parse(&generate(1, 50)).unwrap();
+        assert_eq!(passports.len(), 50);
+        let valid_part_1 = Day04::part_1(&passports);
+        let valid_part_2 = Day04::part_2(&passports);
+        assert!(valid_part_1 > valid_part_2 && valid_part_2 > 0);
+
+        // Every field with a rule gets broken somewhere, but the years are never unparseable
+        let violations: Vec<Violation> = passports.iter().flat_map(|p| p.validate()).collect();
+        for field in FIELDS.iter().filter(|&&field| field != "cid") {
+            assert!(violations.iter().any(|v| v.field() == *field), "{}", field);
+        }
+        assert!(!violations.iter().any(|v| {
+            matches!(v, Violation::Unparseable { field, .. } if field.ends_with("yr"))
+        }));
+    }
+}
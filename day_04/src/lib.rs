@@ -0,0 +1,94 @@
+use crate::data::Passport;
+use aoc_common::{blocks, ParseError, Solution};
+
+pub mod data;
+pub mod generator;
+pub mod reader;
+pub mod schema;
+pub mod validated;
+pub mod validation;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<Passport>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Passport>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(passports: &Vec<Passport>) -> usize {
+        part_1(passports)
+    }
+
+    fn part_2(passports: &Vec<Passport>) -> usize {
+        part_2(passports)
+    }
+}
+
+// How many passports are valid?
+pub fn part_1(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| p.is_valid_part_1()).count()
+}
+
+// How many passports are valid?
+pub fn part_2(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| p.is_valid_part_2()).count()
+}
+
+// Each "chunk" of lines separated by a blank line represents a Passport
+pub fn parse_input(input: &str) -> Result<Vec<Passport>, ParseError> {
+    blocks(input)
+        .into_iter()
+        .map(|(offset, block)| {
+            block
+                .parse()
+                .map_err(|e: ParseError| e.offset_lines(offset))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn example_input() -> String {
+        "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+        byr:1937 iyr:2017 cid:147 hgt:183cm
+
+        iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+        hcl:#cfa07d byr:1929
+
+        hcl:#ae17e1 iyr:2013
+        eyr:2024
+        ecl:brn pid:760753108 byr:1931
+        hgt:179cm
+
+        hcl:#cfa07d eyr:2025 pid:166559648
+        iyr:2011 ecl:brn hgt:59in"
+            .trim()
+            .split("\n")
+            .map(|s| s.trim())
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_part_1() {
+        let passports = parse_input(example_input().as_str()).unwrap();
+        assert_eq!(part_1(&passports), 2);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = example_input().replace("hgt:179cm", "hgt 179cm");
+        assert_eq!(
+            parse_input(&input).unwrap_err(),
+            ParseError::new(10, 1, "hgt", "Expected '<key>:<value>'")
+        );
+    }
+}
@@ -1,63 +1,93 @@
-use crate::data::Passport;
-
-mod data;
+use aoc_common::InputSource;
+use day_04::data::PASSPORT_SCHEMA;
+use day_04::reader::read_batch;
+use day_04::schema::Schema;
+use day_04::validated::to_json;
+use day_04::validation::BatchReport;
+use day_04::Day04;
+use std::process;
 
+// Solves the puzzle as usual, unless given "--report", in which case it summarises what's wrong
+// with the passports. They're checked against the puzzle's rules, or the rules in a TOML or JSON
+// schema file given with "--schema <path>". Malformed records, duplicate keys and keys the schema
+// doesn't know about are listed first, without stopping the rest of the batch from being checked.
+// With "--json" it prints the valid passports as JSON, reading the batch just as leniently, with
+// anything that was skipped or looked odd listed on stderr.
 fn main() {
-    let input = parse_input(include_str!("input.txt"));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = if args.iter().any(|arg| arg == "--report") {
+        report(&args)
+    } else if args.iter().any(|arg| arg == "--json") {
+        export_json(&args)
+    } else {
+        aoc_common::main::<Day04>();
+        return;
+    };
 
-    println!("part_1: {}", part_1(&input));
-    println!("part_2: {}", part_2(&input));
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 }
 
-// How many passports are valid?
-fn part_1(passports: &Vec<Passport>) -> usize {
-    passports.iter().filter(|p| p.is_valid_part_1()).count()
-}
+fn export_json(args: &[String]) -> Result<(), String> {
+    let input_path = args.iter().find(|arg| *arg != "--json");
+    let input = InputSource::from_arg(input_path.map(String::as_str))
+        .read::<Day04>()
+        .map_err(|e| e.to_string())?;
+    let batch = read_batch(&input, &PASSPORT_SCHEMA);
 
-// How many passports are valid?
-fn part_2(passports: &Vec<Passport>) -> usize {
-    passports.iter().filter(|p| p.is_valid_part_2()).count()
-}
+    for malformed in &batch.malformed {
+        eprintln!("Malformed {}", malformed);
+    }
+    for warning in &batch.warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    println!("{}", to_json(&batch.passports));
 
-// Converts each "chunk" that represents a Passport (and may span multiple lines) into a single
-// String with no newlines, and returns it as a Vec.
-fn parse_input(input: &str) -> Vec<Passport> {
-    input
-        .trim()
-        .split("\n\n")
-        .map(|s| s.to_string().replace("\n", " "))
-        .map(|s| s.parse().unwrap())
-        .collect()
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    pub fn example_input() -> String {
-        "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
-        byr:1937 iyr:2017 cid:147 hgt:183cm
-
-        iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
-        hcl:#cfa07d byr:1929
-
-        hcl:#ae17e1 iyr:2013
-        eyr:2024
-        ecl:brn pid:760753108 byr:1931
-        hgt:179cm
-
-        hcl:#cfa07d eyr:2025 pid:166559648
-        iyr:2011 ecl:brn hgt:59in"
-            .trim()
-            .split("\n")
-            .map(|s| s.trim())
-            .collect::<Vec<&str>>()
-            .join("\n")
+fn report(args: &[String]) -> Result<(), String> {
+    let mut schema_path = None;
+    let mut input_path = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" => (),
+            "--schema" => schema_path = Some(args.next().ok_or("Missing value for --schema")?),
+            _ => input_path = Some(arg.as_str()),
+        }
     }
 
-    #[test]
-    fn test_part_1() {
-        let passports = parse_input(example_input().as_str());
-        assert_eq!(part_1(&passports), 2);
+    let schema = match schema_path {
+        None => None,
+        Some(path) => {
+            let s = std::fs::read_to_string(path)
+                .map_err(|e| format!("Couldn't read {}: {}", path, e))?;
+            let schema = if path.ends_with(".json") {
+                Schema::from_json(&s)
+            } else {
+                Schema::from_toml(&s)
+            };
+            Some(schema.map_err(|e| format!("Invalid schema {}: {}", path, e))?)
+        }
+    };
+
+    let schema = schema.as_ref().unwrap_or(&PASSPORT_SCHEMA);
+    let input = InputSource::from_arg(input_path)
+        .read::<Day04>()
+        .map_err(|e| e.to_string())?;
+    let batch = read_batch(&input, schema);
+
+    for malformed in &batch.malformed {
+        println!("Malformed {}", malformed);
     }
+    for warning in &batch.warnings {
+        println!("Warning: {}", warning);
+    }
+    println!("{}", BatchReport::new(schema, &batch.passports));
+
+    Ok(())
 }
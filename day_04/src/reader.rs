@@ -0,0 +1,183 @@
+use crate::data::Passport;
+use crate::schema::Schema;
+use aoc_common::{blocks, ParseError};
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+// Everything that could be read from a batch of passports. Unlike parse_input, a malformed record
+// doesn't stop the rest of the batch from being read: it's left out and reported instead.
+#[derive(Debug)]
+pub struct Batch {
+    pub passports: Vec<Passport>,
+    pub warnings: Vec<Warning>,
+    pub malformed: Vec<Malformed>,
+}
+
+// Something odd about a record that doesn't stop it being read. Records are numbered from 1, and
+// lines are counted from the start of the batch.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Warning {
+    // Only the last value is kept
+    DuplicateKey {
+        record: usize,
+        line: usize,
+        key: String,
+    },
+    // A key that the schema doesn't have a rule for
+    UnknownKey {
+        record: usize,
+        line: usize,
+        key: String,
+    },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::DuplicateKey { record, line, key } => write!(
+                f,
+                "record {}, line {}: duplicate key '{}' (the last value is used)",
+                record, line, key
+            ),
+            Warning::UnknownKey { record, line, key } => {
+                write!(f, "record {}, line {}: unknown key '{}'", record, line, key)
+            }
+        }
+    }
+}
+
+// A record that couldn't be read at all, with the error pointing at where it is in the batch
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Malformed {
+    pub record: usize,
+    pub error: ParseError,
+}
+
+impl Display for Malformed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "record {}, {}", self.record, self.error)
+    }
+}
+
+// Records are separated by one or more blank lines, and lines can end with "\n" or "\r\n" (see
+// aoc_common::blocks). Keys are checked against the schema, which is what makes them "unknown".
+pub fn read_batch(input: &str, schema: &Schema) -> Batch {
+    let mut batch = Batch {
+        passports: vec![],
+        warnings: vec![],
+        malformed: vec![],
+    };
+
+    for (idx, (offset, block)) in blocks(input).into_iter().enumerate() {
+        let record = idx + 1;
+        match block.parse::<Passport>() {
+            Ok(passport) => {
+                batch
+                    .warnings
+                    .extend(check_keys(record, offset, block, schema));
+                batch.passports.push(passport);
+            }
+            Err(error) => batch.malformed.push(Malformed {
+                record,
+                error: error.offset_lines(offset),
+            }),
+        }
+    }
+
+    batch
+}
+
+// Warnings for the keys in a record that has already been parsed, in the order they appear
+fn check_keys(record: usize, offset: usize, block: &str, schema: &Schema) -> Vec<Warning> {
+    let mut seen = HashSet::new();
+    let mut warnings = vec![];
+
+    for (idx, text) in block.lines().enumerate() {
+        let line = offset + idx + 1;
+        let keys = text
+            .split_whitespace()
+            .filter_map(|field| field.split_once(':').map(|(key, _)| key));
+
+        for key in keys {
+            if !seen.insert(key) {
+                warnings.push(Warning::DuplicateKey {
+                    record,
+                    line,
+                    key: key.to_string(),
+                });
+            }
+            if !schema.fields.contains_key(key) {
+                warnings.push(Warning::UnknownKey {
+                    record,
+                    line,
+                    key: key.to_string(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::PASSPORT_SCHEMA;
+
+    #[test]
+    fn test_read_batch() {
+        let input = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd  \r\n\
+                     byr:1937 iyr:2017 cid:147 hgt:183cm\r\n\
+                     \r\n\
+                     \x20 \t\r\n\
+                     \r\n\
+                     iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884\r\n\
+                     hcl:#cfa07d byr:1929 ecl:brn nickname:bob\r\n";
+
+        let batch = read_batch(input, &PASSPORT_SCHEMA);
+        assert_eq!(batch.passports.len(), 2);
+        assert!(batch.passports[0].is_valid_part_2());
+        assert_eq!(batch.passports[1].get("ecl"), Some("brn"));
+        assert_eq!(batch.passports[1].get("pid"), Some("028048884"));
+        assert_eq!(batch.malformed, vec![]);
+        assert_eq!(
+            batch.warnings,
+            vec![
+                Warning::DuplicateKey {
+                    record: 2,
+                    line: 7,
+                    key: "ecl".to_string()
+                },
+                Warning::UnknownKey {
+                    record: 2,
+                    line: 7,
+                    key: "nickname".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            batch.warnings[0].to_string(),
+            "record 2, line 7: duplicate key 'ecl' (the last value is used)"
+        );
+    }
+
+    #[test]
+    fn test_read_batch_malformed() {
+        let input = "byr:1937 iyr:2017\n\nhcl:#cfa07d\nbyr 1929\n\n\n\necl:brn\n";
+
+        let batch = read_batch(input, &PASSPORT_SCHEMA);
+        assert_eq!(batch.passports.len(), 2);
+        assert_eq!(batch.passports[1].get("ecl"), Some("brn"));
+        assert_eq!(
+            batch.malformed,
+            vec![Malformed {
+                record: 2,
+                error: ParseError::new(4, 1, "byr", "Expected '<key>:<value>'")
+            }]
+        );
+        assert_eq!(
+            batch.malformed[0].to_string(),
+            "record 2, line 4, column 1: Expected '<key>:<value>' ('byr')"
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use crate::data::Passport;
+use crate::validation::Violation;
+use aoc_common::ParseError;
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+
+// Rules for each field in a document, keyed by field name. These are written as TOML or JSON (see
+// passport.toml for the puzzle's rules), so other kinds of document can be checked without any
+// code changes.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    pub fields: BTreeMap<String, FieldRule>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(try_from = "RawFieldRule")]
+pub struct FieldRule {
+    pub required: bool,
+    pub kind: FieldKind,
+}
+
+// A rule as it's written, with every key that any type of rule can have. Keys that no type uses are
+// rejected while it's read, and keys that only another type uses are rejected when it's turned into
+// a FieldRule, so a misspelled key can't quietly loosen a rule.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawFieldRule {
+    #[serde(rename = "type")]
+    kind: KindName,
+    #[serde(default = "required_by_default")]
+    required: bool,
+    min: Option<i64>,
+    max: Option<i64>,
+    regex: Option<Pattern>,
+    values: Option<Vec<String>>,
+    units: Option<HashMap<String, Bounds>>,
+}
+
+fn required_by_default() -> bool {
+    true
+}
+
+#[derive(Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+enum KindName {
+    Any,
+    Integer,
+    Pattern,
+    OneOf,
+    Measurement,
+}
+
+// As it's written in a schema
+impl Display for KindName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            KindName::Any => "any",
+            KindName::Integer => "integer",
+            KindName::Pattern => "pattern",
+            KindName::OneOf => "one_of",
+            KindName::Measurement => "measurement",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl TryFrom<RawFieldRule> for FieldRule {
+    type Error = String;
+
+    fn try_from(mut raw: RawFieldRule) -> Result<Self, Self::Error> {
+        let missing = |key: &str| format!("missing field `{}`", key);
+        let kind = match raw.kind {
+            KindName::Any => FieldKind::Any,
+            KindName::Integer => FieldKind::Integer {
+                min: raw.min.take(),
+                max: raw.max.take(),
+            },
+            KindName::Pattern => FieldKind::Pattern {
+                regex: raw.regex.take().ok_or_else(|| missing("regex"))?,
+            },
+            KindName::OneOf => FieldKind::OneOf {
+                values: raw.values.take().ok_or_else(|| missing("values"))?,
+            },
+            KindName::Measurement => FieldKind::Measurement {
+                units: raw.units.take().ok_or_else(|| missing("units"))?,
+            },
+        };
+
+        let unused = [
+            ("min", raw.min.is_some()),
+            ("max", raw.max.is_some()),
+            ("regex", raw.regex.is_some()),
+            ("values", raw.values.is_some()),
+            ("units", raw.units.is_some()),
+        ];
+        match unused.iter().find(|(_, is_set)| *is_set) {
+            Some((key, _)) => Err(format!("field `{}` isn't used by {} rules", key, raw.kind)),
+            None => Ok(FieldRule {
+                required: raw.required,
+                kind,
+            }),
+        }
+    }
+}
+
+// What a field's value has to look like. Integer bounds are inclusive, and either can be left out.
+#[derive(Debug)]
+pub enum FieldKind {
+    Any,
+    Integer { min: Option<i64>, max: Option<i64> },
+    Pattern { regex: Pattern },
+    OneOf { values: Vec<String> },
+    // A whole number followed by its unit (e.g. "183cm"), where each unit has its own bounds
+    Measurement { units: HashMap<String, Bounds> },
+}
+
+#[derive(Deserialize, Eq, PartialEq, Copy, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Bounds {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+impl Bounds {
+    pub fn contains(&self, n: i64) -> bool {
+        self.min.is_none_or(|min| n >= min) && self.max.is_none_or(|max| n <= max)
+    }
+}
+
+// A regex that has to match the whole of a value. It displays as it was written in the schema.
+#[derive(Debug)]
+pub struct Pattern {
+    source: String,
+    regex: Regex,
+}
+
+impl Pattern {
+    pub fn new(source: &str) -> Result<Pattern, regex::Error> {
+        Ok(Pattern {
+            source: source.to_string(),
+            regex: Regex::new(&format!(r"\A(?:{})\z", source))?,
+        })
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        self.regex.is_match(value)
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Pattern::new(&pattern)
+            .map_err(|_| serde::de::Error::custom(format!("invalid regex '{}'", pattern)))
+    }
+}
+
+impl Schema {
+    pub fn from_toml(s: &str) -> Result<Schema, ParseError> {
+        toml::from_str(s).map_err(|e| {
+            let span = e.span().unwrap_or(0..0);
+            let (line, column) = line_and_column(s, span.start);
+            let text = s[span].lines().next().unwrap_or("");
+            ParseError::new(line, column, text, e.message())
+        })
+    }
+
+    pub fn from_json(s: &str) -> Result<Schema, ParseError> {
+        serde_json::from_str(s).map_err(|e| {
+            // The message has the line and column on the end, which ParseError adds back on
+            let message = e.to_string();
+            let reason = message
+                .rsplit_once(" at line ")
+                .map_or(&message[..], |m| m.0);
+            let text = s.lines().nth(e.line().max(1) - 1).unwrap_or("").trim();
+            ParseError::new(e.line(), e.column(), text, reason)
+        })
+    }
+
+    pub fn has_required_fields(&self, passport: &Passport) -> bool {
+        self.fields
+            .iter()
+            .all(|(name, rule)| !rule.required || passport.get(name).is_some())
+    }
+
+    // Required fields have to be there, and every field that's there has to follow its rule
+    pub fn is_valid(&self, passport: &Passport) -> bool {
+        self.validate(passport).is_empty()
+    }
+
+    // Every way in which the passport breaks the schema, in field order
+    pub fn validate(&self, passport: &Passport) -> Vec<Violation> {
+        self.fields
+            .iter()
+            .filter_map(|(name, rule)| match passport.get(name) {
+                Some(value) => rule.kind.check(name, value).err(),
+                None if rule.required => Some(Violation::Missing {
+                    field: name.clone(),
+                }),
+                None => None,
+            })
+            .collect()
+    }
+}
+
+impl FieldKind {
+    pub fn accepts(&self, value: &str) -> bool {
+        self.check("", value).is_ok()
+    }
+
+    pub fn check(&self, field: &str, value: &str) -> Result<(), Violation> {
+        let unparseable = |expected: String| Violation::Unparseable {
+            field: field.to_string(),
+            value: value.to_string(),
+            expected,
+        };
+        let in_bounds = |n: i64, unit: Option<&str>, bounds: Bounds| {
+            if bounds.contains(n) {
+                Ok(())
+            } else {
+                Err(Violation::OutOfRange {
+                    field: field.to_string(),
+                    value: n,
+                    unit: unit.map(str::to_string),
+                    bounds,
+                })
+            }
+        };
+
+        match self {
+            FieldKind::Any => Ok(()),
+            FieldKind::Integer { min, max } => {
+                let n = value
+                    .parse()
+                    .map_err(|_| unparseable("an integer".to_string()))?;
+                in_bounds(
+                    n,
+                    None,
+                    Bounds {
+                        min: *min,
+                        max: *max,
+                    },
+                )
+            }
+            FieldKind::Pattern { regex } if !regex.is_match(value) => {
+                Err(Violation::PatternMismatch {
+                    field: field.to_string(),
+                    value: value.to_string(),
+                    pattern: regex.to_string(),
+                })
+            }
+            FieldKind::Pattern { .. } => Ok(()),
+            FieldKind::OneOf { values } if !values.iter().any(|v| v == value) => {
+                Err(Violation::NotOneOf {
+                    field: field.to_string(),
+                    value: value.to_string(),
+                    values: values.clone(),
+                })
+            }
+            FieldKind::OneOf { .. } => Ok(()),
+            FieldKind::Measurement { units } => {
+                let (number, unit) = split_measurement(value);
+                match (number.parse(), units.get(unit)) {
+                    (Ok(n), Some(bounds)) => in_bounds(n, Some(unit), *bounds),
+                    _ => {
+                        let mut names: Vec<&str> = units.keys().map(String::as_str).collect();
+                        names.sort_unstable();
+                        Err(unparseable(format!(
+                            "a number followed by {}",
+                            names.join(" or ")
+                        )))
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The leading digits, and whatever comes after them
+fn split_measurement(value: &str) -> (&str, &str) {
+    let idx = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    value.split_at(idx)
+}
+
+// The 1-indexed line and column (in characters) of a byte offset into s
+fn line_and_column(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passport(s: &str) -> Passport {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_schema_from_toml() {
+        let schema = Schema::from_toml(include_str!("passport.toml")).unwrap();
+        assert_eq!(schema.fields.len(), 8);
+        assert!(!schema.fields["cid"].required);
+        assert!(schema.fields["byr"].kind.accepts("2002"));
+        assert!(!schema.fields["byr"].kind.accepts("2003"));
+        assert!(!schema.fields["byr"].kind.accepts("two thousand"));
+        assert!(schema.fields["hgt"].kind.accepts("60in"));
+        assert!(!schema.fields["hgt"].kind.accepts("190in"));
+        assert!(!schema.fields["hgt"].kind.accepts("190"));
+        assert!(!schema.fields["hgt"].kind.accepts("cm"));
+        assert!(schema.fields["hcl"].kind.accepts("#123abc"));
+        assert!(!schema.fields["hcl"].kind.accepts("#123abcd"));
+        assert!(!schema.fields["ecl"].kind.accepts("wat"));
+        assert!(!schema.fields["pid"].kind.accepts("0123456789"));
+    }
+
+    #[test]
+    fn test_schema_from_toml_errors() {
+        let error =
+            Schema::from_toml("[fields.a]\ntype = \"integer\"\nmin = \"one\"\n").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert!(error.reason.contains("invalid type"), "{}", error.reason);
+
+        // Misspelled keys are rejected rather than ignored
+        let error = Schema::from_toml("[fields.a]\ntype = \"integer\"\nmn = 1920\n").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert!(
+            error.reason.contains("unknown field `mn`"),
+            "{}",
+            error.reason
+        );
+
+        let error = Schema::from_toml("[fields.a]\ntype = \"any\"\nrequird = false\n").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert!(
+            error.reason.contains("unknown field `requird`"),
+            "{}",
+            error.reason
+        );
+
+        // So are keys that belong to a different type of rule
+        let error =
+            Schema::from_toml("[fields.a]\ntype = \"integer\"\nregex = \"[0-9]+\"\n").unwrap_err();
+        assert_eq!(error.reason, "field `regex` isn't used by integer rules");
+
+        let error = Schema::from_toml("[fields.a]\ntype = \"pattern\"\n").unwrap_err();
+        assert_eq!(error.reason, "missing field `regex`");
+
+        let error =
+            Schema::from_toml("[fields.a]\ntype = \"pattern\"\nregex = \"[a-\"\n").unwrap_err();
+        assert_eq!(error.reason, "invalid regex '[a-'");
+
+        let error = Schema::from_toml("[fields.a]\ntype = \"colour\"\n").unwrap_err();
+        assert!(
+            error.reason.contains("unknown variant `colour`"),
+            "{}",
+            error.reason
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new("a|bc").unwrap();
+        assert!(pattern.is_match("a"));
+        assert!(pattern.is_match("bc"));
+        assert!(!pattern.is_match("ab"));
+        assert!(!pattern.is_match("abc"));
+        assert_eq!(pattern.to_string(), "a|bc");
+        assert!(Pattern::new("[a-").is_err());
+    }
+
+    #[test]
+    fn test_schema_from_json() {
+        let schema = Schema::from_json(
+            r#"{
+                "fields": {
+                    "name": { "type": "pattern", "regex": "[A-Z][a-z]+" },
+                    "age": { "type": "integer", "min": 0 },
+                    "nickname": { "type": "any", "required": false }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert!(schema.is_valid(&passport("name:Scott age:32")));
+        assert!(schema.is_valid(&passport("name:Scott age:32 nickname:scooter")));
+        assert!(!schema.is_valid(&passport("name:scott age:32")));
+        assert!(!schema.is_valid(&passport("name:Scott age:-1")));
+        assert!(!schema.is_valid(&passport("name:Scott")));
+        assert!(schema.has_required_fields(&passport("name:scott age:-1")));
+
+        let error = Schema::from_json("{\n  \"fields\": {\n    \"a\": { \"type\": 5 }\n  }\n}")
+            .unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.text, "\"a\": { \"type\": 5 }");
+        assert!(!error.reason.contains("at line"));
+    }
+}
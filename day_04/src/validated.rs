@@ -0,0 +1,311 @@
+use crate::data::{Passport, PASSPORT_SCHEMA};
+use crate::validation::Violation;
+use serde::{Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+// A passport that follows all of the puzzle's rules, with each field parsed into its proper type
+#[derive(Serialize, Eq, PartialEq, Clone, Debug)]
+pub struct ValidatedPassport {
+    pub birth_year: u16,
+    pub issue_year: u16,
+    pub expiration_year: u16,
+    pub height: Height,
+    pub hair_color: HairColor,
+    pub eye_color: EyeColor,
+    pub passport_id: PassportId,
+    pub country_id: Option<String>,
+}
+
+#[derive(Serialize, Eq, PartialEq, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Height {
+    Cm(u32),
+    In(u32),
+}
+
+const CM_PER_INCH: f64 = 2.54;
+
+impl Height {
+    pub fn centimetres(self) -> f64 {
+        match self {
+            Height::Cm(cm) => cm as f64,
+            Height::In(inches) => inches as f64 * CM_PER_INCH,
+        }
+    }
+
+    pub fn inches(self) -> f64 {
+        match self {
+            Height::Cm(cm) => cm as f64 / CM_PER_INCH,
+            Height::In(inches) => inches as f64,
+        }
+    }
+}
+
+impl FromStr for Height {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected = || "a number followed by cm or in".to_string();
+
+        if let Some(cm) = s.strip_suffix("cm").filter(|cm| is_digits(cm, 10)) {
+            cm.parse().map(Height::Cm).map_err(|_| expected())
+        } else if let Some(inches) = s.strip_suffix("in").filter(|inches| is_digits(inches, 10)) {
+            inches.parse().map(Height::In).map_err(|_| expected())
+        } else {
+            Err(expected())
+        }
+    }
+}
+
+// Serialized as it's written in passports, e.g. "#623a2f"
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct HairColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl FromStr for HairColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected = || "'#' followed by six hex digits".to_string();
+        let hex = s
+            .strip_prefix('#')
+            .filter(|hex| hex.len() == 6 && is_digits(hex, 16))
+            .ok_or_else(expected)?;
+        let channel =
+            |idx: usize| u8::from_str_radix(&hex[idx..(idx + 2)], 16).map_err(|_| expected());
+
+        Ok(HairColor {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        })
+    }
+}
+
+impl Display for HairColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl Serialize for HairColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Serialize, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum EyeColor {
+    #[serde(rename = "amb")]
+    Amber,
+    #[serde(rename = "blu")]
+    Blue,
+    #[serde(rename = "brn")]
+    Brown,
+    #[serde(rename = "gry")]
+    Gray,
+    #[serde(rename = "grn")]
+    Green,
+    #[serde(rename = "hzl")]
+    Hazel,
+    #[serde(rename = "oth")]
+    Other,
+}
+
+impl FromStr for EyeColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "amb" => Ok(EyeColor::Amber),
+            "blu" => Ok(EyeColor::Blue),
+            "brn" => Ok(EyeColor::Brown),
+            "gry" => Ok(EyeColor::Gray),
+            "grn" => Ok(EyeColor::Green),
+            "hzl" => Ok(EyeColor::Hazel),
+            "oth" => Ok(EyeColor::Other),
+            _ => Err("an eye color".to_string()),
+        }
+    }
+}
+
+// Nine digits, which can include leading zeroes (so it's kept as a string)
+#[derive(Serialize, Eq, PartialEq, Clone, Debug)]
+#[serde(transparent)]
+pub struct PassportId(String);
+
+impl PassportId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PassportId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 9 && s.chars().all(|c| c.is_ascii_digit()) {
+            Ok(PassportId(s.to_string()))
+        } else {
+            Err("nine digits".to_string())
+        }
+    }
+}
+
+// The passport has to follow the puzzle's schema, and every value has to parse into its type
+impl TryFrom<&Passport> for ValidatedPassport {
+    type Error = Vec<Violation>;
+
+    fn try_from(passport: &Passport) -> Result<Self, Self::Error> {
+        let violations = PASSPORT_SCHEMA.validate(passport);
+        if !violations.is_empty() {
+            return Err(violations);
+        }
+
+        Ok(ValidatedPassport {
+            birth_year: field(passport, "byr", parse_year)?,
+            issue_year: field(passport, "iyr", parse_year)?,
+            expiration_year: field(passport, "eyr", parse_year)?,
+            height: field(passport, "hgt", str::parse)?,
+            hair_color: field(passport, "hcl", str::parse)?,
+            eye_color: field(passport, "ecl", str::parse)?,
+            passport_id: field(passport, "pid", str::parse)?,
+            country_id: passport.get("cid").map(str::to_string),
+        })
+    }
+}
+
+// The schema has already checked each field, but they still have to parse into their types
+fn field<T, F>(passport: &Passport, name: &str, parse: F) -> Result<T, Vec<Violation>>
+where
+    F: Fn(&str) -> Result<T, String>,
+{
+    let value = passport.get(name).ok_or_else(|| {
+        vec![Violation::Missing {
+            field: name.to_string(),
+        }]
+    })?;
+
+    parse(value).map_err(|expected| {
+        vec![Violation::Unparseable {
+            field: name.to_string(),
+            value: value.to_string(),
+            expected,
+        }]
+    })
+}
+
+fn parse_year(s: &str) -> Result<u16, String> {
+    match is_digits(s, 10) {
+        true => s.parse().map_err(|_| "a year".to_string()),
+        false => Err("a year".to_string()),
+    }
+}
+
+// Whether s is nothing but digits in the given radix. Parsing a number on its own also allows a
+// leading '+', which never belongs in a passport.
+fn is_digits(s: &str, radix: u32) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_digit(radix))
+}
+
+// Every valid passport in the batch as a JSON array, skipping the invalid ones
+pub fn to_json(passports: &[Passport]) -> String {
+    let validated: Vec<ValidatedPassport> = passports
+        .iter()
+        .filter_map(|passport| ValidatedPassport::try_from(passport).ok())
+        .collect();
+
+    serde_json::to_string_pretty(&validated).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validated(s: &str) -> Result<ValidatedPassport, Vec<Violation>> {
+        ValidatedPassport::try_from(&s.parse::<Passport>().unwrap())
+    }
+
+    #[test]
+    fn test_validated_passport() {
+        let passport = validated(
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f cid:100",
+        )
+        .unwrap();
+
+        assert_eq!(
+            passport,
+            ValidatedPassport {
+                birth_year: 1980,
+                issue_year: 2012,
+                expiration_year: 2030,
+                height: Height::In(74),
+                hair_color: HairColor {
+                    r: 0x62,
+                    g: 0x3a,
+                    b: 0x2f
+                },
+                eye_color: EyeColor::Green,
+                passport_id: PassportId("087499704".to_string()),
+                country_id: Some("100".to_string()),
+            }
+        );
+        assert_eq!(passport.height.centimetres(), 187.96);
+        assert_eq!(passport.hair_color.to_string(), "#623a2f");
+        assert_eq!(passport.passport_id.as_str(), "087499704");
+
+        let violations =
+            validated("pid:087499704 hgt:74 ecl:grn iyr:2012 eyr:2030 hcl:#623a2f").unwrap_err();
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_field_types() {
+        assert_eq!("150cm".parse(), Ok(Height::Cm(150)));
+        assert_eq!(Height::Cm(254).inches(), 100.0);
+        assert!("150".parse::<Height>().is_err());
+        assert!("cm".parse::<Height>().is_err());
+        assert!("#12345g".parse::<HairColor>().is_err());
+        assert!("#1234567".parse::<HairColor>().is_err());
+        assert!("#éé3456".parse::<HairColor>().is_err());
+        assert!("#+1+2+3".parse::<HairColor>().is_err());
+        assert!("+150cm".parse::<Height>().is_err());
+        assert!("+60in".parse::<Height>().is_err());
+        assert_eq!(parse_year("1990"), Ok(1990));
+        assert!(parse_year("+990").is_err());
+        assert_eq!("hzl".parse(), Ok(EyeColor::Hazel));
+        assert!("red".parse::<EyeColor>().is_err());
+        assert!("12345678a".parse::<PassportId>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let passports: Vec<Passport> = [
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f",
+            "pid:087499704 hgt:74 ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&passports)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "birth_year": 1980,
+                "issue_year": 2012,
+                "expiration_year": 2030,
+                "height": { "in": 74 },
+                "hair_color": "#623a2f",
+                "eye_color": "grn",
+                "passport_id": "087499704",
+                "country_id": null
+            }])
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::data::Passport;
+use crate::schema::{Bounds, Schema};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+// One way in which a passport breaks its schema
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Violation {
+    Missing {
+        field: String,
+    },
+    Unparseable {
+        field: String,
+        value: String,
+        expected: String,
+    },
+    OutOfRange {
+        field: String,
+        value: i64,
+        unit: Option<String>,
+        bounds: Bounds,
+    },
+    PatternMismatch {
+        field: String,
+        value: String,
+        pattern: String,
+    },
+    NotOneOf {
+        field: String,
+        value: String,
+        values: Vec<String>,
+    },
+}
+
+impl Violation {
+    pub fn field(&self) -> &str {
+        match self {
+            Violation::Missing { field }
+            | Violation::Unparseable { field, .. }
+            | Violation::OutOfRange { field, .. }
+            | Violation::PatternMismatch { field, .. }
+            | Violation::NotOneOf { field, .. } => field,
+        }
+    }
+
+    // What went wrong, without the value itself, so that the same problem on different passports
+    // can be counted together
+    pub fn reason(&self) -> String {
+        let problem = match self {
+            Violation::Missing { .. } => "is missing".to_string(),
+            Violation::Unparseable { .. } => "can't be parsed".to_string(),
+            Violation::OutOfRange { unit: None, .. } => "is out of range".to_string(),
+            Violation::OutOfRange {
+                unit: Some(unit), ..
+            } => format!("is out of range ({})", unit),
+            Violation::PatternMismatch { .. } => "doesn't match its pattern".to_string(),
+            Violation::NotOneOf { .. } => "isn't one of the allowed values".to_string(),
+        };
+
+        format!("{} {}", self.field(), problem)
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::Missing { field } => write!(f, "{} is missing", field),
+            Violation::Unparseable {
+                field,
+                value,
+                expected,
+            } => write!(f, "{} '{}' isn't {}", field, value, expected),
+            Violation::OutOfRange {
+                field,
+                value,
+                unit,
+                bounds,
+            } => {
+                let unit = unit.as_deref().unwrap_or("");
+                write!(
+                    f,
+                    "{} {}{} isn't {}",
+                    field,
+                    value,
+                    unit,
+                    bounds.describe(unit)
+                )
+            }
+            Violation::PatternMismatch {
+                field,
+                value,
+                pattern,
+            } => write!(f, "{} '{}' doesn't match /{}/", field, value, pattern),
+            Violation::NotOneOf {
+                field,
+                value,
+                values,
+            } => write!(
+                f,
+                "{} '{}' isn't one of {}",
+                field,
+                value,
+                values.join(", ")
+            ),
+        }
+    }
+}
+
+impl Bounds {
+    fn describe(&self, unit: &str) -> String {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => format!("{}{} to {}{}", min, unit, max, unit),
+            (Some(min), None) => format!("at least {}{}", min, unit),
+            (None, Some(max)) => format!("at most {}{}", max, unit),
+            (None, None) => "anything".to_string(),
+        }
+    }
+}
+
+// How a whole batch of passports did, with the reasons passports failed from most to least common
+#[derive(Eq, PartialEq, Debug)]
+pub struct BatchReport {
+    pub passports: usize,
+    pub valid: usize,
+    pub reasons: Vec<(String, usize)>,
+}
+
+impl BatchReport {
+    pub fn new(schema: &Schema, passports: &[Passport]) -> BatchReport {
+        let mut valid = 0;
+        let mut reasons: HashMap<String, usize> = HashMap::new();
+
+        for passport in passports {
+            let violations = schema.validate(passport);
+            if violations.is_empty() {
+                valid += 1;
+            }
+            for violation in violations {
+                *reasons.entry(violation.reason()).or_insert(0) += 1;
+            }
+        }
+
+        let mut reasons: Vec<(String, usize)> = reasons.into_iter().collect();
+        reasons.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        BatchReport {
+            passports: passports.len(),
+            valid,
+            reasons,
+        }
+    }
+}
+
+impl Display for BatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} passports are valid",
+            self.valid, self.passports
+        )?;
+        for (reason, count) in &self.reasons {
+            write!(f, "\n{:>6}  {}", count, reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::PASSPORT_SCHEMA;
+
+    fn violations(s: &str) -> Vec<String> {
+        let passport: Passport = s.parse().unwrap();
+        passport.validate().iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            violations("pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f"),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            violations("pid:08749970 hgt:74 ecl:red iyr:2009 eyr:soon byr:1980"),
+            vec![
+                "ecl 'red' isn't one of amb, blu, brn, gry, grn, hzl, oth",
+                "eyr 'soon' isn't an integer",
+                "hcl is missing",
+                "hgt '74' isn't a number followed by cm or in",
+                "iyr 2009 isn't 2010 to 2020",
+                "pid '08749970' doesn't match /[0-9]{9}/",
+            ]
+        );
+        assert_eq!(
+            violations("pid:087499704 hgt:194cm ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f"),
+            vec!["hgt 194cm isn't 150cm to 193cm"]
+        );
+    }
+
+    #[test]
+    fn test_batch_report() {
+        let passports: Vec<Passport> = [
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f",
+            "pid:087499704 hgt:80in ecl:grn iyr:2012 eyr:2030 hcl:#623a2f",
+            "pid:087499704 hgt:90in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+        let report = BatchReport::new(&PASSPORT_SCHEMA, &passports);
+        assert_eq!(report.valid, 1);
+        assert_eq!(
+            report.reasons,
+            vec![
+                ("hgt is out of range (in)".to_string(), 2),
+                ("byr is missing".to_string(), 1)
+            ]
+        );
+        assert_eq!(
+            report.to_string(),
+            "1 of 3 passports are valid\n     2  hgt is out of range (in)\n     1  byr is missing"
+        );
+    }
+}
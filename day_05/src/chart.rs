@@ -0,0 +1,222 @@
+use crate::data::BoardingPass;
+use crate::layout::SeatLayout;
+use aoc_common::grid::Coord;
+use aoc_common::Grid;
+use std::fmt::{self, Display};
+
+// How many boarding passes there are for each seat on an aircraft, with a row of the grid for each
+// row of seats
+#[derive(Debug)]
+pub struct SeatingChart {
+    layout: SeatLayout,
+    passes: Grid<usize>,
+}
+
+// A seat as it's drawn on the chart
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Seat {
+    Empty,
+    // Empty, but the seats either side of it (by id) are taken, like the puzzle's missing seat
+    Missing,
+    Taken,
+    // More than one boarding pass is for this seat
+    Duplicated,
+}
+
+impl Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Seat::Empty => write!(f, "."),
+            Seat::Missing => write!(f, "?"),
+            Seat::Taken => write!(f, "#"),
+            Seat::Duplicated => write!(f, "!"),
+        }
+    }
+}
+
+// The chart keeps a count for every seat, so it's only made for layouts of up to 2^20 (about a
+// million) seats
+pub const MAX_CHART_BITS: u32 = 20;
+
+// Why a chart couldn't be made
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum ChartError {
+    TooManySeats { bits: u32 },
+    DifferentLayout { boarding_pass: String },
+}
+
+impl Display for ChartError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChartError::TooManySeats { bits } => write!(
+                f,
+                "Can't chart a layout with {} bits of seats (the most is {})",
+                bits, MAX_CHART_BITS
+            ),
+            ChartError::DifferentLayout { boarding_pass } => {
+                write!(f, "{} is for a different layout", boarding_pass)
+            }
+        }
+    }
+}
+
+impl SeatingChart {
+    // Every boarding pass has to be for the given layout
+    pub fn new(
+        layout: SeatLayout,
+        boarding_passes: &[BoardingPass],
+    ) -> Result<SeatingChart, ChartError> {
+        let bits = layout.pass_length() as u32;
+        if bits > MAX_CHART_BITS {
+            return Err(ChartError::TooManySeats { bits });
+        }
+
+        let mut passes = Grid::new(
+            layout.cols(),
+            layout.rows(),
+            vec![0; layout.cols() * layout.rows()],
+        );
+
+        for bp in boarding_passes {
+            if bp.layout != layout {
+                return Err(ChartError::DifferentLayout {
+                    boarding_pass: bp.to_string(),
+                });
+            }
+            *passes.get_mut(coord(bp)).unwrap() += 1;
+        }
+
+        Ok(SeatingChart { layout, passes })
+    }
+
+    pub fn seat(&self, bp: &BoardingPass) -> Seat {
+        match self.passes_for(bp.id()) {
+            0 if self.is_missing(bp.id()) => Seat::Missing,
+            0 => Seat::Empty,
+            1 => Seat::Taken,
+            _ => Seat::Duplicated,
+        }
+    }
+
+    // Every seat without a boarding pass, in id order
+    pub fn empty_seats(&self) -> Vec<BoardingPass> {
+        self.seats()
+            .filter(|bp| self.passes_for(bp.id()) == 0)
+            .collect()
+    }
+
+    // The empty seats whose neighbours (the seats with ids 1 either side) are both taken
+    pub fn missing_seats(&self) -> Vec<BoardingPass> {
+        self.seats().filter(|bp| self.is_missing(bp.id())).collect()
+    }
+
+    // Seats with more than one boarding pass, along with how many passes there are for them
+    pub fn duplicates(&self) -> Vec<(BoardingPass, usize)> {
+        self.seats()
+            .map(|bp| (bp, self.passes_for(bp.id())))
+            .filter(|(_bp, count)| *count > 1)
+            .collect()
+    }
+
+    fn seats(&self) -> impl Iterator<Item = BoardingPass> + '_ {
+        (0..(self.layout.rows() * self.layout.cols())).map(move |id| self.layout.boarding_pass(id))
+    }
+
+    // Ids outside of the aircraft have no passes
+    fn passes_for(&self, id: usize) -> usize {
+        if id < self.layout.rows() * self.layout.cols() {
+            *self
+                .passes
+                .get(coord(&self.layout.boarding_pass(id)))
+                .unwrap()
+        } else {
+            0
+        }
+    }
+
+    fn is_missing(&self, id: usize) -> bool {
+        id > 0
+            && self.passes_for(id) == 0
+            && self.passes_for(id - 1) > 0
+            && self.passes_for(id + 1) > 0
+    }
+}
+
+fn coord(bp: &BoardingPass) -> Coord {
+    (bp.col as isize, bp.row as isize)
+}
+
+// The cabin from the front row to the back, one line per row
+impl Display for SeatingChart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let seats = self.passes.map(|(x, y), _count| {
+            self.seat(&BoardingPass {
+                row: y as usize,
+                col: x as usize,
+                layout: self.layout,
+            })
+        });
+
+        write!(f, "{}", seats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart(ids: &[usize]) -> SeatingChart {
+        let layout = SeatLayout::new(3, ['F', 'B'], 2, ['L', 'R']).unwrap();
+        let passes: Vec<BoardingPass> = ids.iter().map(|&id| layout.boarding_pass(id)).collect();
+        SeatingChart::new(layout, &passes).unwrap()
+    }
+
+    fn ids(passes: &[BoardingPass]) -> Vec<usize> {
+        passes.iter().map(|bp| bp.id()).collect()
+    }
+
+    #[test]
+    fn test_seating_chart() {
+        let chart = chart(&[5, 6, 7, 9, 10, 11, 12, 13, 13, 14, 16, 17, 17, 17]);
+
+        assert_eq!(
+            ids(&chart.empty_seats()),
+            vec![0, 1, 2, 3, 4, 8, 15, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31]
+        );
+        assert_eq!(ids(&chart.missing_seats()), vec![8, 15]);
+
+        let duplicates: Vec<(usize, usize)> = chart
+            .duplicates()
+            .iter()
+            .map(|(bp, count)| (bp.id(), *count))
+            .collect();
+        assert_eq!(duplicates, vec![(13, 2), (17, 3)]);
+
+        assert_eq!(
+            chart.to_string(),
+            "....\n.###\n?###\n#!#?\n#!..\n....\n....\n...."
+        );
+    }
+
+    #[test]
+    fn test_seating_chart_edges() {
+        // The first and last seats only have one neighbour, so they're never missing
+        let chart = chart(&[1, 30]);
+        assert_eq!(chart.missing_seats(), vec![]);
+        assert_eq!(chart.empty_seats().len(), 30);
+        assert_eq!(chart.duplicates(), vec![]);
+    }
+
+    #[test]
+    fn test_seating_chart_errors() {
+        let huge = SeatLayout::new(40, ['F', 'B'], 3, ['L', 'R']).unwrap();
+        assert_eq!(
+            SeatingChart::new(huge, &[]).unwrap_err(),
+            ChartError::TooManySeats { bits: 43 }
+        );
+
+        let small = SeatLayout::new(3, ['F', 'B'], 2, ['L', 'R']).unwrap();
+        let error = SeatingChart::new(small, &[BoardingPass::new(44, 5)]).unwrap_err();
+        assert_eq!(error.to_string(), "FBFBBFFRLR is for a different layout");
+    }
+}
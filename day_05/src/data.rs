@@ -1,68 +1,112 @@
+use crate::layout::SeatLayout;
+use aoc_common::ParseError;
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
 pub struct BoardingPass {
     pub row: usize,
     pub col: usize,
+    pub layout: SeatLayout,
 }
 
 impl BoardingPass {
-    // Multiply the row by 8, then add the column
+    // A seat on the puzzle's aircraft
+    pub fn new(row: usize, col: usize) -> BoardingPass {
+        BoardingPass {
+            row,
+            col,
+            layout: SeatLayout::PUZZLE,
+        }
+    }
+
+    // Multiply the row by the number of columns (8 on the puzzle's aircraft), then add the column
     pub fn id(&self) -> usize {
-        self.row * 8 + self.col
+        self.layout.id(self.row, self.col)
     }
 }
 
+// Boarding passes for the puzzle's aircraft: the first 7 characters (F/B) encode the row, and the
+// last 3 (L/R) encode the column. Other aircraft are parsed with SeatLayout#parse.
 impl FromStr for BoardingPass {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let row = binary_partition(s.get(0..=6).unwrap(), (0, 127), 'F', 'B')?;
-        let col = binary_partition(s.get(7..=9).unwrap(), (0, 7), 'L', 'R')?;
-        Ok(Self { row, col })
+        SeatLayout::PUZZLE.parse(s)
     }
 }
 
-fn binary_partition(
-    sequence: &str,
-    mut range: (usize, usize),
-    take_low: char,
-    take_high: char,
-) -> Result<usize, String> {
-    for char in sequence.chars() {
-        if char == take_low {
-            range.1 = range.1 - (range.1 - range.0 + 1) / 2
-        } else if char == take_high {
-            range.0 = range.0 + (range.1 - range.0 + 1) / 2
-        } else {
-            return Err(format!("Unknown char: '{}'", char).to_string());
-        }
+// The row's bits, then the column's bits, each written with its layout's letters
+impl Display for BoardingPass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let layout = &self.layout;
+        write_bits(f, self.row, layout.row_bits(), layout.row_letters())?;
+        write_bits(f, self.col, layout.col_bits(), layout.col_letters())
+    }
+}
+
+fn write_bits(f: &mut fmt::Formatter, value: usize, bits: u32, letters: [char; 2]) -> fmt::Result {
+    for bit in (0..bits).rev() {
+        write!(f, "{}", letters[(value >> bit) & 1])?;
     }
 
-    Ok(range.0)
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_boarding_pass_from_str() {
         assert_eq!(
             "FBFBBFFRLR".parse::<BoardingPass>().unwrap(),
-            BoardingPass { row: 44, col: 5 }
+            BoardingPass::new(44, 5)
         );
         assert_eq!(
             "BFFFBBFRRR".parse::<BoardingPass>().unwrap(),
-            BoardingPass { row: 70, col: 7 }
+            BoardingPass::new(70, 7)
         );
         assert_eq!(
             "FFFBBBFRRR".parse::<BoardingPass>().unwrap(),
-            BoardingPass { row: 14, col: 7 }
+            BoardingPass::new(14, 7)
         );
         assert_eq!(
             "BBFFBBFRLL".parse::<BoardingPass>().unwrap(),
-            BoardingPass { row: 102, col: 4 }
+            BoardingPass::new(102, 4)
+        );
+    }
+
+    #[test]
+    fn test_boarding_pass_from_str_errors() {
+        assert_eq!(
+            "FBFBBF".parse::<BoardingPass>(),
+            Err(ParseError::new(
+                1,
+                1,
+                "FBFBBF",
+                "Expected 10 characters, found 6"
+            ))
         );
+        assert_eq!(
+            "FBFBBFFRLÉ".parse::<BoardingPass>(),
+            Err(ParseError::new(1, 10, "É", "Expected 'L' or 'R'"))
+        );
+        assert_eq!("FBFLBFFRLR".parse::<BoardingPass>().unwrap_err().column, 4);
+    }
+
+    #[test]
+    fn test_boarding_pass_display() {
+        assert_eq!(BoardingPass::new(44, 5).to_string(), "FBFBBFFRLR");
+        assert_eq!(BoardingPass::new(102, 4).to_string(), "BBFFBBFRLL");
+    }
+
+    proptest! {
+        #[test]
+        fn test_boarding_pass_round_trip(row in 0..128_usize, col in 0..8_usize) {
+            let boarding_pass = BoardingPass::new(row, col);
+            prop_assert_eq!(boarding_pass.to_string().parse(), Ok(boarding_pass));
+        }
     }
 }
@@ -0,0 +1,57 @@
+use crate::layout::SeatLayout;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 800;
+
+// The boarding passes for size passengers, with consecutive seat ids apart from one missing seat
+// (yours), which is never in the first or last row. The plane only has 1024 seats and your seat
+// needs a full row either side of it, so size is kept between 24 and 1000.
+pub fn generate(seed: u64, size: usize) -> String {
+    let (passes, _your_id) = boarding_passes(seed, size);
+
+    passes.iter().map(|pass| format!("{}\n", pass)).collect()
+}
+
+// The boarding passes, along with the id of the seat that was left out
+fn boarding_passes(seed: u64, size: usize) -> (Vec<String>, usize) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.clamp(24, 1000);
+
+    let first_id = rng.gen_range(0..(1024 - size));
+    let last_id = first_id + size;
+    let your_id = rng.gen_range(((first_id / 8 + 1) * 8)..(last_id / 8 * 8));
+
+    let mut passes: Vec<String> = (first_id..=last_id)
+        .filter(|&id| id != your_id)
+        .map(|id| SeatLayout::PUZZLE.boarding_pass(id).to_string())
+        .collect();
+    passes.shuffle(&mut rng);
+
+    (passes, your_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day05, SeatReport};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let passes = Day05::parse(&generate(1, 50)).unwrap();
+        let (_, your_id) = boarding_passes(1, 50);
+        assert_eq!(passes.len(), 50);
+        assert_eq!(Day05::part_2(&passes), SeatReport::Id(your_id));
+
+        // Your seat isn't in the first or last row
+        let ids: Vec<usize> = passes.iter().map(|pass| pass.id()).collect();
+        let (first_id, last_id) = (*ids.iter().min().unwrap(), *ids.iter().max().unwrap());
+        assert!(first_id / 8 < your_id / 8 && your_id / 8 < last_id / 8);
+        assert_eq!(crate::part_1(&passes), Some(last_id));
+    }
+}
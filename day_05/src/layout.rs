@@ -0,0 +1,327 @@
+use crate::data::BoardingPass;
+use aoc_common::{parse_lines, ParseError};
+use std::error::Error;
+use std::fmt::{self, Display};
+
+// The shape of an aircraft, and how its boarding passes are written. A boarding pass is row_bits
+// letters that pick the row, then col_bits letters that pick the column. Each letter takes the
+// lower or upper half of what's left, which is the same as reading a binary number where the
+// first letter of the pair is a 0 and the second is a 1. The fields are private so that every
+// layout goes through new's checks.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct SeatLayout {
+    row_bits: u32,
+    col_bits: u32,
+    row_letters: [char; 2],
+    col_letters: [char; 2],
+}
+
+// Seat ids have to fit in a usize
+const MAX_BITS: u32 = usize::BITS - 1;
+
+impl SeatLayout {
+    // 128 rows picked with F/B and 8 columns picked with L/R
+    pub const PUZZLE: SeatLayout = SeatLayout {
+        row_bits: 7,
+        col_bits: 3,
+        row_letters: ['F', 'B'],
+        col_letters: ['L', 'R'],
+    };
+
+    pub fn new(
+        row_bits: u32,
+        row_letters: [char; 2],
+        col_bits: u32,
+        col_letters: [char; 2],
+    ) -> Result<SeatLayout, String> {
+        match row_bits.checked_add(col_bits) {
+            Some(bits) if bits <= MAX_BITS => (),
+            _ => {
+                return Err(format!(
+                    "A layout can't have more than {} bits, not {} + {}",
+                    MAX_BITS, row_bits, col_bits
+                ))
+            }
+        }
+        for letters in [row_letters, col_letters] {
+            if letters[0] == letters[1] {
+                return Err(format!("'{}' can't mean both halves", letters[0]));
+            }
+        }
+
+        Ok(SeatLayout {
+            row_bits,
+            col_bits,
+            row_letters,
+            col_letters,
+        })
+    }
+
+    pub fn row_bits(&self) -> u32 {
+        self.row_bits
+    }
+
+    pub fn col_bits(&self) -> u32 {
+        self.col_bits
+    }
+
+    // The letters for the lower and upper half of the rows
+    pub fn row_letters(&self) -> [char; 2] {
+        self.row_letters
+    }
+
+    // The letters for the lower and upper half of the columns
+    pub fn col_letters(&self) -> [char; 2] {
+        self.col_letters
+    }
+
+    pub fn rows(&self) -> usize {
+        1 << self.row_bits
+    }
+
+    pub fn cols(&self) -> usize {
+        1 << self.col_bits
+    }
+
+    // How many letters there are on a boarding pass
+    pub fn pass_length(&self) -> usize {
+        (self.row_bits + self.col_bits) as usize
+    }
+
+    // Each row's seats get consecutive ids, starting from row * cols
+    pub fn id(&self, row: usize, col: usize) -> usize {
+        row * self.cols() + col
+    }
+
+    // The boarding pass for a seat id, which has to be on the aircraft
+    pub fn boarding_pass(&self, id: usize) -> BoardingPass {
+        assert!(id < self.rows() * self.cols(), "No seat has id {}", id);
+        BoardingPass {
+            row: id / self.cols(),
+            col: id % self.cols(),
+            layout: *self,
+        }
+    }
+
+    // Reads the whole boarding pass as one binary number, which is the seat's id. The length is
+    // checked before any letters are read.
+    pub fn decode(&self, s: &str) -> Result<BoardingPass, DecodeError> {
+        let found = s.chars().count();
+        if found != self.pass_length() {
+            return Err(DecodeError::WrongLength {
+                expected: self.pass_length(),
+                found,
+            });
+        }
+
+        let mut id = 0;
+        for (position, letter) in s.chars().enumerate() {
+            let letters = if position < self.row_bits as usize {
+                self.row_letters
+            } else {
+                self.col_letters
+            };
+            let bit = if letter == letters[0] {
+                0
+            } else if letter == letters[1] {
+                1
+            } else {
+                return Err(DecodeError::UnexpectedLetter {
+                    position,
+                    found: letter,
+                    expected: letters,
+                });
+            };
+            id = id << 1 | bit;
+        }
+
+        Ok(self.boarding_pass(id))
+    }
+
+    // Same, pointing any error at the offending part of the line
+    pub fn parse(&self, s: &str) -> Result<BoardingPass, ParseError> {
+        self.decode(s).map_err(|e| {
+            let offending = match e {
+                DecodeError::WrongLength { .. } => s,
+                DecodeError::UnexpectedLetter {
+                    position, found, ..
+                } => {
+                    let (idx, _) = s.char_indices().nth(position).unwrap();
+                    &s[idx..(idx + found.len_utf8())]
+                }
+            };
+            ParseError::in_line(s, offending, e)
+        })
+    }
+
+    // One boarding pass per line, for reading a lot of them at once
+    pub fn decode_all(&self, input: &str) -> Result<Vec<BoardingPass>, ParseError> {
+        parse_lines(input, |line| self.parse(line))
+    }
+}
+
+// Why a boarding pass couldn't be decoded. Positions count characters from 0.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum DecodeError {
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    UnexpectedLetter {
+        position: usize,
+        found: char,
+        expected: [char; 2],
+    },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::WrongLength { expected, found } => {
+                write!(f, "Expected {} characters, found {}", expected, found)
+            }
+            DecodeError::UnexpectedLetter { expected, .. } => {
+                write!(f, "Expected '{}' or '{}'", expected[0], expected[1])
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // A small plane with 16 rows picked with A/Z, and 4 seats across picked with 0/1
+    fn small_plane() -> SeatLayout {
+        SeatLayout::new(4, ['A', 'Z'], 2, ['0', '1']).unwrap()
+    }
+
+    #[test]
+    fn test_seat_layout() {
+        let layout = small_plane();
+        assert_eq!((layout.rows(), layout.cols()), (16, 4));
+
+        let boarding_pass = layout.parse("ZAZA01").unwrap();
+        assert_eq!((boarding_pass.row, boarding_pass.col), (10, 1));
+        assert_eq!(boarding_pass.id(), 41);
+        assert_eq!(boarding_pass.to_string(), "ZAZA01");
+        assert_eq!(layout.boarding_pass(41), boarding_pass);
+
+        assert_eq!(
+            layout.parse("ZAZA011"),
+            Err(ParseError::new(
+                1,
+                1,
+                "ZAZA011",
+                "Expected 6 characters, found 7"
+            ))
+        );
+        assert_eq!(
+            layout.parse("ZAFA01"),
+            Err(ParseError::new(1, 3, "F", "Expected 'A' or 'Z'"))
+        );
+        assert_eq!(
+            layout.parse("ZAZAL1"),
+            Err(ParseError::new(1, 5, "L", "Expected '0' or '1'"))
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let layout = SeatLayout::PUZZLE;
+        assert_eq!(layout.decode("FBFBBFFRLR"), Ok(BoardingPass::new(44, 5)));
+        assert_eq!(
+            layout.decode(""),
+            Err(DecodeError::WrongLength {
+                expected: 10,
+                found: 0
+            })
+        );
+        assert_eq!(
+            layout.decode("FBFBBFFRLRR"),
+            Err(DecodeError::WrongLength {
+                expected: 10,
+                found: 11
+            })
+        );
+        // The length is checked before the letters
+        assert_eq!(
+            layout.decode("XXX"),
+            Err(DecodeError::WrongLength {
+                expected: 10,
+                found: 3
+            })
+        );
+        assert_eq!(
+            layout.decode("FBFBBFRRLR"),
+            Err(DecodeError::UnexpectedLetter {
+                position: 6,
+                found: 'R',
+                expected: ['F', 'B'],
+            })
+        );
+        assert_eq!(
+            layout.decode("FBFBBFFRLé"),
+            Err(DecodeError::UnexpectedLetter {
+                position: 9,
+                found: 'é',
+                expected: ['L', 'R'],
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_all() {
+        let passes = SeatLayout::PUZZLE
+            .decode_all("FBFBBFFRLR\nBFFFBBFRRR\n")
+            .unwrap();
+        assert_eq!(
+            passes,
+            vec![BoardingPass::new(44, 5), BoardingPass::new(70, 7)]
+        );
+
+        assert_eq!(
+            SeatLayout::PUZZLE.decode_all("FBFBBFFRLR\nBFFFBBFRRF\n"),
+            Err(ParseError::new(2, 10, "F", "Expected 'L' or 'R'"))
+        );
+    }
+
+    #[test]
+    fn test_seat_layout_edges() {
+        // Every seat in a single row
+        let layout = SeatLayout::new(0, ['F', 'B'], 3, ['L', 'R']).unwrap();
+        assert_eq!(layout.parse("RLR").unwrap().id(), 5);
+        assert_eq!(layout.boarding_pass(5).to_string(), "RLR");
+
+        // One seat per row
+        let layout = SeatLayout::new(3, ['F', 'B'], 0, ['L', 'R']).unwrap();
+        assert_eq!(layout.parse("BFB").unwrap().id(), 5);
+        assert_eq!(layout.boarding_pass(5).to_string(), "BFB");
+
+        assert!(SeatLayout::new(40, ['F', 'B'], 40, ['L', 'R']).is_err());
+        assert_eq!(
+            SeatLayout::new(u32::MAX, ['F', 'B'], 1, ['L', 'R']),
+            Err(format!(
+                "A layout can't have more than {} bits, not {} + 1",
+                MAX_BITS,
+                u32::MAX
+            ))
+        );
+        assert_eq!(
+            SeatLayout::new(7, ['F', 'F'], 3, ['L', 'R']),
+            Err("'F' can't mean both halves".to_string())
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_seat_layout_round_trip(id in 0..64_usize) {
+            let boarding_pass = small_plane().boarding_pass(id);
+            prop_assert_eq!(boarding_pass.id(), id);
+            prop_assert_eq!(small_plane().parse(&boarding_pass.to_string()), Ok(boarding_pass));
+        }
+    }
+}
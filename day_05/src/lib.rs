@@ -0,0 +1,115 @@
+use crate::chart::{ChartError, SeatingChart};
+use crate::data::BoardingPass;
+use crate::layout::SeatLayout;
+use aoc_common::{ParseError, Solution};
+use std::fmt::{self, Display};
+
+pub mod chart;
+pub mod data;
+pub mod generator;
+pub mod layout;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<BoardingPass>;
+    type Answer = SeatReport;
+
+    fn parse(input: &str) -> Result<Vec<BoardingPass>, ParseError> {
+        SeatLayout::PUZZLE.decode_all(input)
+    }
+
+    fn part_1(boarding_passes: &Vec<BoardingPass>) -> SeatReport {
+        part_1(boarding_passes).map_or(SeatReport::NoSeat, SeatReport::Id)
+    }
+
+    fn part_2(boarding_passes: &Vec<BoardingPass>) -> SeatReport {
+        part_2(boarding_passes)
+    }
+}
+
+// The seat id the puzzle asks for, or why there isn't exactly one
+#[derive(Eq, PartialEq, Debug)]
+pub enum SeatReport {
+    Id(usize),
+    NoSeat,
+    // More than one seat fits, in id order
+    Candidates(Vec<usize>),
+    NoChart(ChartError),
+}
+
+impl Display for SeatReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeatReport::Id(id) => write!(f, "{}", id),
+            SeatReport::NoSeat => write!(f, "No seat found"),
+            SeatReport::Candidates(ids) => {
+                let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+                write!(f, "{} seats fit: {}", ids.len(), ids.join(", "))
+            }
+            SeatReport::NoChart(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+// What is the highest seat ID on a boarding pass?
+pub fn part_1(boarding_passes: &[BoardingPass]) -> Option<usize> {
+    boarding_passes.iter().map(|bp| bp.id()).max()
+}
+
+// It's a completely full flight, so your seat should be the only missing boarding pass in your
+// list. However, there's a catch: some of the seats at the very front and back of the plane don't
+// exist on this aircraft, so they'll be missing from your list as well. Your seat wasn't at the
+// very front or back, though; the seats with IDs +1 and -1 from yours will be in your list. What is
+// the ID of your seat?
+pub fn part_2(boarding_passes: &[BoardingPass]) -> SeatReport {
+    let chart = match SeatingChart::new(SeatLayout::PUZZLE, boarding_passes) {
+        Ok(chart) => chart,
+        Err(error) => return SeatReport::NoChart(error),
+    };
+    let mut ids: Vec<usize> = chart.missing_seats().iter().map(|bp| bp.id()).collect();
+
+    match ids.len() {
+        0 => SeatReport::NoSeat,
+        1 => SeatReport::Id(ids.remove(0)),
+        _ => SeatReport::Candidates(ids),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passes(ids: &[usize]) -> Vec<BoardingPass> {
+        ids.iter()
+            .map(|&id| SeatLayout::PUZZLE.boarding_pass(id))
+            .collect()
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&passes(&[357, 567, 119, 820])), Some(820));
+        assert_eq!(part_1(&[]), None);
+        assert_eq!(Day05::part_1(&vec![]).to_string(), "No seat found");
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&passes(&[20, 21, 23, 24])), SeatReport::Id(22));
+        assert_eq!(part_2(&passes(&[20, 21, 22])), SeatReport::NoSeat);
+        assert_eq!(part_2(&[]), SeatReport::NoSeat);
+
+        let report = part_2(&passes(&[20, 22, 24]));
+        assert_eq!(report, SeatReport::Candidates(vec![21, 23]));
+        assert_eq!(report.to_string(), "2 seats fit: 21, 23");
+
+        let layout = SeatLayout::new(3, ['F', 'B'], 2, ['L', 'R']).unwrap();
+        assert_eq!(
+            part_2(&[layout.boarding_pass(5)]).to_string(),
+            "FFBLR is for a different layout"
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use aoc_common::ParseError;
+use std::fmt::{self, Display};
+use std::ops::{BitAnd, BitOr};
+use std::str::FromStr;
+
+// A set of questions from a-z, as a bitset with bit 0 for 'a' up to bit 25 for 'z'
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct Answers(u32);
+
+impl Answers {
+    pub const NONE: Answers = Answers(0);
+    pub const ALL: Answers = Answers((1 << 26) - 1);
+
+    // Questions outside of a-z are never in the set
+    pub fn contains(self, question: char) -> bool {
+        question_bit(question).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    // In alphabetical order
+    pub fn questions(self) -> impl Iterator<Item = char> {
+        ('a'..='z').filter(move |&question| self.contains(question))
+    }
+}
+
+fn question_bit(question: char) -> Option<u32> {
+    if question.is_ascii_lowercase() {
+        Some(1 << (question as u32 - 'a' as u32))
+    } else {
+        None
+    }
+}
+
+impl BitOr for Answers {
+    type Output = Answers;
+
+    fn bitor(self, other: Answers) -> Answers {
+        Answers(self.0 | other.0)
+    }
+}
+
+impl BitAnd for Answers {
+    type Output = Answers;
+
+    fn bitand(self, other: Answers) -> Answers {
+        Answers(self.0 & other.0)
+    }
+}
+
+// The questions that one person answered "yes" to, e.g. "abx"
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::NONE;
+
+        for (idx, question) in s.char_indices() {
+            let bit = question_bit(question).ok_or_else(|| {
+                ParseError::in_line(
+                    s,
+                    &s[idx..(idx + question.len_utf8())],
+                    "Expected a question from a-z",
+                )
+            })?;
+            answers.0 |= bit;
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.questions()
+            .try_for_each(|question| write!(f, "{}", question))
+    }
+}
+
+// The answers of everyone in a group, one person per line
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Group {
+    pub members: Vec<Answers>,
+}
+
+impl Group {
+    // The questions that at least k members answered "yes" to. k = 1 gives the questions that
+    // anyone answered, and k = the group's size gives the ones that everyone answered. Every
+    // question has been answered by at least 0 members, even in an empty group.
+    pub fn answered_by_at_least(&self, k: usize) -> Answers {
+        match k {
+            0 => Answers::ALL,
+            1 => self.answered_by_anyone(),
+            k if k == self.members.len() => self.answered_by_everyone(),
+            k => {
+                let histogram = self.histogram();
+                let mut answers = Answers::NONE;
+                for (idx, count) in histogram.iter().enumerate() {
+                    if *count >= k {
+                        answers.0 |= 1 << idx;
+                    }
+                }
+                answers
+            }
+        }
+    }
+
+    pub fn answered_by_anyone(&self) -> Answers {
+        self.members
+            .iter()
+            .fold(Answers::NONE, |answers, member| answers | *member)
+    }
+
+    // A group with no members hasn't answered anything
+    pub fn answered_by_everyone(&self) -> Answers {
+        match self.members.split_first() {
+            Some((first, rest)) => rest
+                .iter()
+                .fold(*first, |answers, member| answers & *member),
+            None => Answers::NONE,
+        }
+    }
+
+    // How many members answered "yes" to each question, from 'a' to 'z'
+    pub fn histogram(&self) -> [usize; 26] {
+        let mut histogram = [0; 26];
+        for member in &self.members {
+            for (idx, count) in histogram.iter_mut().enumerate() {
+                *count += (member.0 >> idx & 1) as usize;
+            }
+        }
+
+        histogram
+    }
+}
+
+impl FromStr for Group {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseError::new(
+                1,
+                1,
+                s,
+                "Expected at least one person's answers",
+            ));
+        }
+
+        let members = s
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| line.parse().map_err(|e: ParseError| e.offset_lines(idx)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Group { members })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(s: &str) -> Answers {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_answers() {
+        let abx = answers("xab");
+        assert_eq!(abx.len(), 3);
+        assert!(abx.contains('x'));
+        assert!(!abx.contains('c'));
+        assert!(!abx.contains('A'));
+        assert_eq!(abx.to_string(), "abx");
+        assert_eq!((abx | answers("cz")).to_string(), "abcxz");
+        assert_eq!((abx & answers("bcx")).to_string(), "bx");
+        assert!(answers("").is_empty());
+        assert_eq!(Answers::ALL.len(), 26);
+        assert_eq!(
+            "abC".parse::<Answers>(),
+            Err(ParseError::new(1, 3, "C", "Expected a question from a-z"))
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let group: Group = "abc\nabd\nae\nf".parse().unwrap();
+
+        assert_eq!(group.answered_by_anyone().to_string(), "abcdef");
+        assert_eq!(group.answered_by_everyone().to_string(), "");
+        assert_eq!(group.answered_by_at_least(0), Answers::ALL);
+        assert_eq!(group.answered_by_at_least(1), group.answered_by_anyone());
+        assert_eq!(group.answered_by_at_least(2).to_string(), "ab");
+        assert_eq!(group.answered_by_at_least(3).to_string(), "a");
+        assert_eq!(group.answered_by_at_least(4), group.answered_by_everyone());
+        assert_eq!(group.answered_by_at_least(5), Answers::NONE);
+
+        let histogram = group.histogram();
+        assert_eq!(&histogram[..7], &[3, 2, 1, 1, 1, 1, 0]);
+        assert_eq!(histogram.iter().sum::<usize>(), 9);
+
+        let empty = Group { members: vec![] };
+        assert_eq!(empty.answered_by_everyone(), Answers::NONE);
+        assert_eq!(empty.answered_by_anyone(), Answers::NONE);
+        assert_eq!(empty.answered_by_at_least(0), Answers::ALL);
+        assert_eq!(empty.histogram(), [0; 26]);
+
+        assert_eq!(
+            "".parse::<Group>(),
+            Err(ParseError::new(
+                1,
+                1,
+                "",
+                "Expected at least one person's answers"
+            ))
+        );
+        assert_eq!(
+            "abc\nab1".parse::<Group>(),
+            Err(ParseError::new(2, 3, "1", "Expected a question from a-z"))
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 480;
+
+// The answers of size groups of 1 to 5 people. Everyone in a group answers some shared questions,
+// plus a few of their own.
+pub fn generate(seed: u64, size: usize) -> String {
+    let groups: Vec<String> = group_answers(seed, size)
+        .iter()
+        .map(|(members, _shared)| members.join("\n"))
+        .collect();
+
+    groups.join("\n\n") + "\n"
+}
+
+// Each group's answers, one string per person, along with the questions that were shared
+fn group_answers(seed: u64, size: usize) -> Vec<(Vec<String>, Vec<char>)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut questions: Vec<char> = ('a'..='z').collect();
+
+    (0..size)
+        .map(|_| {
+            questions.shuffle(&mut rng);
+            let shared = rng.gen_range(0..=10);
+
+            let members = (0..rng.gen_range(1..=5))
+                .map(|_| {
+                    let own = rng.gen_range(if shared == 0 { 1 } else { 0 }..=5);
+                    let mut answers: Vec<char> = questions[..shared]
+                        .iter()
+                        .chain(questions[shared..].choose_multiple(&mut rng, own))
+                        .cloned()
+                        .collect();
+                    answers.shuffle(&mut rng);
+                    answers.into_iter().collect::<String>()
+                })
+                .collect();
+
+            (members, questions[..shared].to_vec())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let groups = Day06::parse(&generate(1, 50)).unwrap();
+        assert_eq!(groups.len(), 50);
+        for (group, (_, shared)) in groups.iter().zip(group_answers(1, 50)) {
+            assert!((1..=5).contains(&group.members.len()));
+            let everyone = group.answered_by_everyone();
+            assert!(shared.iter().all(|&question| everyone.contains(question)));
+        }
+        assert!(Day06::part_1(&groups) > Day06::part_2(&groups));
+    }
+}
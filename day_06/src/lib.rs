@@ -0,0 +1,85 @@
+use crate::data::Group;
+use aoc_common::{blocks, ParseError, Solution};
+
+pub mod data;
+pub mod generator;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<Group>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Group>, ParseError> {
+        parse_groups(input)
+    }
+
+    fn part_1(groups: &Vec<Group>) -> usize {
+        part_1(groups)
+    }
+
+    fn part_2(groups: &Vec<Group>) -> usize {
+        part_2(groups)
+    }
+}
+
+// For each group, count the number of questions to which ANYONE answered "yes". What is the sum of
+// those counts?
+pub fn part_1(groups: &[Group]) -> usize {
+    groups
+        .iter()
+        .map(|group| group.answered_by_anyone().len())
+        .sum()
+}
+
+// For each group, count the number of questions to which EVERYONE answered "yes". What is the sum
+// of those counts?
+pub fn part_2(groups: &[Group]) -> usize {
+    groups
+        .iter()
+        .map(|group| group.answered_by_everyone().len())
+        .sum()
+}
+
+// Each line is one person's answers (questions a-z), and groups are separated by blank lines
+pub fn parse_groups(input: &str) -> Result<Vec<Group>, ParseError> {
+    blocks(input)
+        .into_iter()
+        .map(|(offset, block)| {
+            block
+                .parse()
+                .map_err(|e: ParseError| e.offset_lines(offset))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<Group> {
+        parse_groups(include_str!("test_input.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&example()), 11);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&example()), 6);
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        assert_eq!(example().len(), 5);
+        assert_eq!(
+            parse_groups("abc\n\na\nB"),
+            Err(ParseError::new(4, 1, "B", "Expected a question from a-z"))
+        );
+    }
+}
@@ -1,13 +1,15 @@
+use aoc_common::{parse_number, ParseError};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 pub type Color = String;
 pub type BagRules = HashMap<Color, BagRule>;
 pub type Rule = (usize, Color);
 
-#[derive(Debug)]
+#[derive(PartialEq, Debug)]
 pub struct BagRule {
     pub color: Color,
     pub rules: Vec<Rule>,
@@ -36,26 +38,120 @@ impl BagRule {
     }
 }
 
+// For example: "light red bags contain 1 bright white bag, 2 muted yellow bags."
 impl FromStr for BagRule {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         lazy_static! {
-            static ref REGEX: Regex = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
+            static ref REGEX: Regex = Regex::new(r"\A(\d+) (\w+ \w+) bags?\z").unwrap();
         }
 
-        let color = s.split(' ').take(2).collect::<Vec<&str>>().join(" ");
+        let (color, contents) = s.split_once(" bags contain ").ok_or_else(|| {
+            ParseError::in_line(s, s, "Expected '<color> bags contain <contents>.'")
+        })?;
+        let contents = contents
+            .strip_suffix('.')
+            .ok_or_else(|| ParseError::in_line(s, &s[s.len()..], "Expected '.'"))?;
         let mut rules = vec![];
 
-        if !s.contains("contain no other bags") {
-            for cap in REGEX.captures_iter(s) {
+        if contents != "no other bags" {
+            for content in contents.split(", ") {
+                let cap = REGEX.captures(content).ok_or_else(|| {
+                    ParseError::in_line(s, content, "Expected '<count> <color> bag(s)'")
+                })?;
+                let count = cap.get(1).unwrap().as_str();
+
                 rules.push((
-                    cap.get(1).unwrap().as_str().parse().unwrap(),
+                    parse_number(count).map_err(|e| e.within(s, count))?,
                     cap.get(2).unwrap().as_str().to_string(),
                 ));
             }
         }
 
-        Ok(BagRule { color, rules })
+        Ok(BagRule {
+            color: color.to_string(),
+            rules,
+        })
+    }
+}
+
+impl Display for BagRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bags contain ", self.color)?;
+        if self.rules.is_empty() {
+            return write!(f, "no other bags.");
+        }
+
+        let contents: Vec<String> = self
+            .rules
+            .iter()
+            .map(|(count, color)| {
+                let bags = if *count == 1 { "bag" } else { "bags" };
+                format!("{} {} {}", count, color, bags)
+            })
+            .collect();
+        write!(f, "{}.", contents.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_bag_rule_from_str() {
+        let bag_rule: BagRule = "light red bags contain 1 bright white bag, 2 muted yellow bags."
+            .parse()
+            .unwrap();
+        assert_eq!(bag_rule.color, "light red");
+        assert_eq!(
+            bag_rule.rules,
+            vec![
+                (1, "bright white".to_string()),
+                (2, "muted yellow".to_string())
+            ]
+        );
+
+        let bag_rule: BagRule = "faded blue bags contain no other bags.".parse().unwrap();
+        assert!(bag_rule.rules.is_empty());
+
+        assert_eq!(
+            "light red bags contain 1 bright white bag, two muted yellow bags.".parse::<BagRule>(),
+            Err(ParseError::new(
+                1,
+                44,
+                "two muted yellow bags",
+                "Expected '<count> <color> bag(s)'"
+            ))
+        );
+        assert_eq!(
+            "faded blue bags contain no other bags"
+                .parse::<BagRule>()
+                .unwrap_err()
+                .column,
+            38
+        );
+    }
+
+    #[test]
+    fn test_bag_rule_display() {
+        let rule = "light red bags contain 1 bright white bag, 2 muted yellow bags.";
+        assert_eq!(rule.parse::<BagRule>().unwrap().to_string(), rule);
+
+        let rule = "faded blue bags contain no other bags.";
+        assert_eq!(rule.parse::<BagRule>().unwrap().to_string(), rule);
+    }
+
+    proptest! {
+        #[test]
+        fn test_bag_rule_round_trip(
+            color in "[a-z]+ [a-z]+",
+            rules in prop::collection::vec((any::<usize>(), "[a-z]+ [a-z]+"), 0..5),
+        ) {
+            let bag_rule = BagRule { color, rules };
+            prop_assert_eq!(bag_rule.to_string().parse(), Ok(bag_rule));
+        }
     }
 }
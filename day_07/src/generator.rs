@@ -0,0 +1,142 @@
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 594;
+
+const ADJECTIVES: [&str; 12] = [
+    "light", "dark", "bright", "muted", "faded", "dotted", "vibrant", "pale", "plaid", "striped",
+    "wavy", "dim",
+];
+const COLORS: [&str; 12] = [
+    "red", "orange", "yellow", "green", "blue", "indigo", "violet", "white", "black", "gold",
+    "silver", "tan",
+];
+
+// Rules for size bag colors (at least 1), one of which is shiny gold. Bags only ever contain
+// colors that come after them in a hidden ordering, so no bag can end up inside itself, and shiny
+// gold sits somewhere in the first half of that ordering.
+pub fn generate(seed: u64, size: usize) -> String {
+    let (rules, _order) = rules_in_order(seed, size);
+
+    rules.iter().map(|rule| format!("{}\n", rule)).collect()
+}
+
+// The rules, along with every color in the hidden ordering
+fn rules_in_order(seed: u64, size: usize) -> (Vec<String>, Vec<String>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let mut colors: Vec<String> = (0..size).map(color).collect();
+    let shiny_gold_idx = rng.gen_range((size / 4)..=(size / 2).min(size - 1));
+    colors[shiny_gold_idx] = "shiny gold".to_string();
+
+    let mut contents: Vec<Vec<usize>> = (0..size)
+        .map(|idx| {
+            let later = size - idx - 1;
+            let amount = rng.gen_range(0..=later.min(4));
+            index::sample(&mut rng, later, amount)
+                .into_iter()
+                .map(|later_idx| idx + 1 + later_idx)
+                .collect()
+        })
+        .collect();
+
+    // So few bags are involved with any one color that shiny gold would often be left out
+    // altogether, so make sure it holds something and that a few bags hold it
+    if contents[shiny_gold_idx].is_empty() && shiny_gold_idx + 1 < size {
+        contents[shiny_gold_idx].push(rng.gen_range((shiny_gold_idx + 1)..size));
+    }
+    for idx in index::sample(&mut rng, shiny_gold_idx, shiny_gold_idx.min(3)) {
+        if !contents[idx].contains(&shiny_gold_idx) {
+            contents[idx].push(shiny_gold_idx);
+        }
+    }
+
+    let mut rules: Vec<String> = colors
+        .iter()
+        .zip(&contents)
+        .map(|(color, contents)| {
+            let contents: Vec<String> = contents
+                .iter()
+                .map(|&idx| {
+                    let count = rng.gen_range(1..=4);
+                    let plural = if count == 1 { "" } else { "s" };
+                    format!("{} {} bag{}", count, colors[idx], plural)
+                })
+                .collect();
+
+            if contents.is_empty() {
+                format!("{} bags contain no other bags.", color)
+            } else {
+                format!("{} bags contain {}.", color, contents.join(", "))
+            }
+        })
+        .collect();
+    rules.shuffle(&mut rng);
+
+    (rules, colors)
+}
+
+// Every combination of adjective and color is used before numbering the adjectives, which keeps
+// each color to two words
+fn color(idx: usize) -> String {
+    let adjective = ADJECTIVES[idx % ADJECTIVES.len()];
+    let color = COLORS[(idx / ADJECTIVES.len()) % COLORS.len()];
+
+    match idx / (ADJECTIVES.len() * COLORS.len()) {
+        0 => format!("{} {}", adjective, color),
+        n => format!("{}{} {}", adjective, n, color),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_color() {
+        assert_eq!(color(0), "light red");
+        assert_eq!(color(13), "dark orange");
+        assert_eq!(color(144), "light1 red");
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let bag_rules = Day07::parse(&generate(1, 50)).unwrap();
+        let (_, order) = rules_in_order(1, 50);
+        assert_eq!(bag_rules.len(), 50);
+
+        // Bags only contain colors that come after them
+        let position = |color: &str| order.iter().position(|c| c == color).unwrap();
+        for bag_rule in bag_rules.values() {
+            let idx = position(&bag_rule.color);
+            assert!(bag_rule
+                .rules
+                .iter()
+                .all(|(_, color)| position(color) > idx));
+        }
+
+        // Shiny gold is in the first half, holds something, and a few bags hold it
+        assert!((12..=25).contains(&position("shiny gold")));
+        assert!(!bag_rules["shiny gold"].rules.is_empty());
+        let holders = bag_rules
+            .values()
+            .filter(|bag_rule| bag_rule.rules.iter().any(|(_, c)| c == "shiny gold"))
+            .count();
+        assert!(holders >= 3);
+        assert!(Day07::part_1(&bag_rules) > 0);
+        assert!(Day07::part_2(&bag_rules) > 0);
+
+        // Shiny gold is always there, even if it's the only color
+        let bag_rules = Day07::parse(&generate(1, 0)).unwrap();
+        assert_eq!(bag_rules.keys().collect::<Vec<_>>(), vec!["shiny gold"]);
+        assert_eq!(Day07::part_1(&bag_rules), 0);
+        assert_eq!(Day07::part_2(&bag_rules), 0);
+    }
+}
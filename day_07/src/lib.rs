@@ -0,0 +1,92 @@
+pub mod data;
+pub mod generator;
+
+use crate::data::{BagRule, BagRules};
+use aoc_common::{parse_lines, ParseError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = BagRules;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<BagRules, ParseError> {
+        parse_bag_rules(input)
+    }
+
+    fn part_1(bag_rules: &BagRules) -> usize {
+        part_1(bag_rules)
+    }
+
+    fn part_2(bag_rules: &BagRules) -> usize {
+        part_2(bag_rules)
+    }
+}
+
+// How many bag colors can eventually contain at least one shiny gold bag?
+pub fn part_1(bag_rules: &BagRules) -> usize {
+    let shiny_gold = "shiny gold".to_string();
+
+    bag_rules
+        .iter()
+        .filter(|(_color, bag_rule)| bag_rule.can_contain(&shiny_gold, bag_rules))
+        .count()
+}
+
+// How many individual bags are required inside your single shiny gold bag? parse_bag_rules makes
+// sure there's a rule for it.
+pub fn part_2(bag_rules: &BagRules) -> usize {
+    let bag_rule = bag_rules.get(&"shiny gold".to_string()).unwrap();
+    bag_rule.total_bags(bag_rules)
+}
+
+pub fn parse_bag_rules(s: &str) -> Result<BagRules, ParseError> {
+    let bag_rules: BagRules = parse_lines(s, str::parse::<BagRule>)?
+        .into_iter()
+        .map(|bag_rule| (bag_rule.color.clone(), bag_rule))
+        .collect();
+
+    if !bag_rules.contains_key("shiny gold") {
+        return Err(ParseError::new(
+            1,
+            1,
+            "",
+            "Expected a rule for shiny gold bags",
+        ));
+    }
+    Ok(bag_rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> BagRules {
+        parse_bag_rules(include_str!("test_input.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&test_input()), 4);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&test_input()), 32);
+    }
+
+    #[test]
+    fn test_parse_without_shiny_gold() {
+        assert_eq!(
+            parse_bag_rules("light red bags contain 1 bright white bag.\n").unwrap_err(),
+            ParseError::new(1, 1, "", "Expected a rule for shiny gold bags")
+        );
+        assert_eq!(
+            parse_bag_rules("").unwrap_err(),
+            ParseError::new(1, 1, "", "Expected a rule for shiny gold bags")
+        );
+    }
+}
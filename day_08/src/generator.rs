@@ -0,0 +1,116 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 630;
+
+// A boot code program of size instructions (at least 2). It starts out with only forward jumps, so
+// it runs straight through to the end; then one instruction along the way is replaced with a jump
+// back to an earlier one, which makes it loop forever. Turning that jmp into a nop fixes it, and
+// it's picked so that no other single jmp or nop can be swapped to fix it instead.
+pub fn generate(seed: u64, size: usize) -> String {
+    let (instructions, _broken_idx) = boot_code(seed, size);
+
+    instructions
+        .iter()
+        .map(|(op, arg)| format!("{} {:+}\n", op, arg))
+        .collect()
+}
+
+// The instructions, along with the index of the jmp that breaks them
+fn boot_code(seed: u64, size: usize) -> (Vec<(&'static str, i64)>, usize) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(2);
+
+    let instructions: Vec<(&'static str, i64)> = (0..size)
+        .map(|idx| match if idx == 0 { 0 } else { rng.gen_range(0..4) } {
+            0 | 1 => ("acc", rng.gen_range(-50..=50)),
+            2 => ("jmp", rng.gen_range(1..=((size - idx) as i64).min(8))),
+            _ => ("nop", rng.gen_range(-20..=20)),
+        })
+        .collect();
+
+    let mut path = vec![];
+    let mut pointer = 0;
+    while pointer < size {
+        path.push(pointer);
+        match instructions[pointer] {
+            ("jmp", arg) => pointer += arg as usize,
+            _ => pointer += 1,
+        }
+    }
+
+    // Breaking the second instruction on the path always leaves only one fix, since the only
+    // instruction before it is an acc, so this doesn't go on forever
+    loop {
+        let broken = rng.gen_range(1..path.len());
+        let (broken_idx, target_idx) = (path[broken], path[rng.gen_range(0..broken)]);
+        let mut broken_instructions = instructions.clone();
+        broken_instructions[broken_idx] = ("jmp", target_idx as i64 - broken_idx as i64);
+
+        if fixes(&broken_instructions) == vec![broken_idx] {
+            return (broken_instructions, broken_idx);
+        }
+    }
+}
+
+// Every instruction that fixes the program when it's swapped between jmp and nop
+fn fixes(instructions: &[(&'static str, i64)]) -> Vec<usize> {
+    (0..instructions.len())
+        .filter(|&idx| {
+            let mut swapped = instructions.to_vec();
+            swapped[idx].0 = match swapped[idx].0 {
+                "jmp" => "nop",
+                "nop" => "jmp",
+                _ => return false,
+            };
+            terminates(&swapped)
+        })
+        .collect()
+}
+
+// Whether the program runs past its last instruction (or jumps anywhere outside of it) before
+// running any instruction twice
+fn terminates(instructions: &[(&str, i64)]) -> bool {
+    let mut visited = vec![false; instructions.len()];
+    let mut pointer = 0;
+
+    while let Some(&(op, arg)) = instructions.get(pointer as usize) {
+        if visited[pointer as usize] {
+            return false;
+        }
+        visited[pointer as usize] = true;
+        pointer += if op == "jmp" { arg } else { 1 };
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::Operation;
+    use crate::program::Program;
+    use crate::{program_terminates, Day08};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let instructions = Day08::parse(&generate(1, 50)).unwrap();
+        let (_, broken_idx) = boot_code(1, 50);
+        assert_eq!(instructions.len(), 50);
+        assert!(!program_terminates(&mut Program::new(instructions.clone())));
+        Day08::part_1(&instructions);
+
+        // The broken instruction jumps backwards, and turning it into a nop fixes the program
+        assert_eq!(instructions[broken_idx].op, Operation::Jmp);
+        assert!(instructions[broken_idx].arg <= 0);
+        let mut fixed = instructions.clone();
+        fixed[broken_idx].op = Operation::Nop;
+        let mut program = Program::new(fixed);
+        assert!(program_terminates(&mut program));
+        assert_eq!(Day08::part_2(&instructions), program.accumulator);
+    }
+}
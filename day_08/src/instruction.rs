@@ -1,3 +1,5 @@
+use aoc_common::{parse_number, ParseError};
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -14,27 +16,39 @@ pub enum Operation {
     Nop,
 }
 
+impl Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Acc => write!(f, "acc"),
+            Operation::Jmp => write!(f, "jmp"),
+            Operation::Nop => write!(f, "nop"),
+        }
+    }
+}
+
+// Written the way the puzzle input writes it, with a sign on the argument. This shows the current
+// operation rather than the original line, which may have been swapped since.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:+}", self.op, self.arg)
+    }
+}
+
 impl FromStr for Instruction {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(' ');
-        let op_str = split
-            .nth(0)
-            .ok_or(format!("Invalid instruction: '{}'", s))?;
-        let arg_str = split
-            .nth(0)
-            .ok_or(format!("Invalid instruction: '{}'", s))?;
+        let (op_str, arg_str) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::in_line(s, s, "Expected '<operation> <argument>'"))?;
 
         let op = match op_str {
             "acc" => Ok(Operation::Acc),
             "jmp" => Ok(Operation::Jmp),
             "nop" => Ok(Operation::Nop),
-            _ => Err(format!("Unknown operation: '{}'", op_str)),
+            _ => Err(ParseError::in_line(s, op_str, "Unknown operation")),
         }?;
-        let arg = arg_str
-            .parse()
-            .or(Err(format!("Invalid argument: '{}'", arg_str)))?;
+        let arg = parse_number(arg_str).map_err(|e| e.within(s, arg_str))?;
 
         Ok(Instruction {
             original: s.to_string(),
@@ -47,6 +61,7 @@ impl FromStr for Instruction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn instruction_from_str() {
@@ -74,5 +89,41 @@ mod tests {
                 arg: -99,
             }),
         );
+        assert_eq!(
+            "jump +4".parse::<Instruction>(),
+            Err(ParseError::new(1, 1, "jump", "Unknown operation")),
+        );
+        assert_eq!("acc 1.5".parse::<Instruction>().unwrap_err().column, 5);
+        assert_eq!("nop".parse::<Instruction>().unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn instruction_display() {
+        let mut instruction: Instruction = "jmp -3".parse().unwrap();
+        assert_eq!(instruction.to_string(), "jmp -3");
+        instruction.op = Operation::Nop;
+        assert_eq!(instruction.to_string(), "nop -3");
+        assert_eq!(
+            "acc 0".parse::<Instruction>().unwrap().to_string(),
+            "acc +0"
+        );
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            Just(Operation::Acc),
+            Just(Operation::Jmp),
+            Just(Operation::Nop)
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_round_trip(op in operation(), arg in any::<i64>()) {
+            let line = Instruction { original: String::new(), op, arg }.to_string();
+            let instruction: Instruction = line.parse().unwrap();
+            prop_assert_eq!(instruction.to_string(), line.clone());
+            prop_assert_eq!(instruction.original, line);
+        }
     }
 }
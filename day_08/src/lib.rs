@@ -0,0 +1,111 @@
+pub mod generator;
+pub mod instruction;
+pub mod program;
+
+use crate::instruction::Operation;
+use crate::program::{Accumulator, Instructions, Program};
+use aoc_common::{parse_lines, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Instructions;
+    type Answer = Accumulator;
+
+    fn parse(input: &str) -> Result<Instructions, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_1(instructions: &Instructions) -> Accumulator {
+        part_1(instructions.clone())
+    }
+
+    fn part_2(instructions: &Instructions) -> Accumulator {
+        part_2(instructions.clone())
+    }
+}
+
+// Immediately before any instruction is executed a second time, what value is in the accumulator?
+pub fn part_1(instructions: Instructions) -> i64 {
+    let mut program = Program::new(instructions);
+    let mut run_pointers = HashSet::new();
+
+    loop {
+        let next_pointer = program.pointer;
+
+        if run_pointers.contains(&next_pointer) {
+            return program.accumulator;
+        } else {
+            run_pointers.insert(next_pointer);
+            program.run_next_instruction();
+        }
+    }
+}
+
+// Fix the program so that it terminates normally by changing exactly one jmp (to nop) or nop
+// (to jmp). What is the value of the accumulator after the program terminates?
+pub fn part_2(instructions: Instructions) -> i64 {
+    use Operation::*;
+
+    for (idx, instruction) in instructions.iter().enumerate() {
+        // Swap jmp/nop operations
+        let modified_instructions = if instruction.op == Jmp {
+            let mut ins = instructions.clone();
+            ins[idx].op = Nop;
+            ins
+        } else if instruction.op == Nop {
+            let mut ins = instructions.clone();
+            ins[idx].op = Jmp;
+            ins
+        } else {
+            continue;
+        };
+
+        let mut program = Program::new(modified_instructions);
+        if program_terminates(&mut program) {
+            return program.accumulator;
+        }
+    }
+
+    panic!("Swapping instructions didn't work!");
+}
+
+pub fn program_terminates(program: &mut Program) -> bool {
+    let mut run_pointers = HashSet::new();
+
+    loop {
+        let next_pointer = program.pointer;
+
+        if run_pointers.contains(&next_pointer) {
+            return false;
+        } else {
+            run_pointers.insert(next_pointer);
+            if !program.run_next_instruction() {
+                return true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn example_instructions() -> Instructions {
+        Day08::parse(include_str!("test_input.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(example_instructions()), 5);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(example_instructions()), 8);
+    }
+}
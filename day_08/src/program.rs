@@ -15,7 +15,7 @@ impl Program {
         Program {
             accumulator: 0,
             pointer: 0,
-            instructions: instructions,
+            instructions,
         }
     }
 
@@ -0,0 +1,122 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+const PREAMBLE_SIZE: usize = 25;
+const MAX_VALID: usize = 500;
+
+// size XMAS numbers (at least 27). After the preamble, every number is the sum of two different
+// numbers among the 25 before it, up until the invalid number, which is the sum of a contiguous run
+// of earlier numbers instead (and of no other run). Since the sums grow exponentially, there are at
+// most 500 valid numbers; anything after the invalid number is random, but more than half of it so
+// that no run of them can add up to it.
+pub fn generate(seed: u64, size: usize) -> String {
+    let (numbers, _run) = xmas_numbers(seed, size);
+
+    numbers
+        .iter()
+        .map(|number| format!("{}\n", number))
+        .collect()
+}
+
+// The numbers, along with the start and end (exclusive) of the run that adds up to the invalid
+// number
+fn xmas_numbers(seed: u64, size: usize) -> (Vec<usize>, (usize, usize)) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(PREAMBLE_SIZE + 2);
+
+    let mut numbers: Vec<usize> = (1..=50).collect();
+    numbers.shuffle(&mut rng);
+    numbers.truncate(PREAMBLE_SIZE);
+
+    while numbers.len() < (size - 1).min(PREAMBLE_SIZE + MAX_VALID) {
+        let window = &numbers[(numbers.len() - PREAMBLE_SIZE)..];
+        let a = *window.choose(&mut rng).unwrap();
+        let b = *window
+            .iter()
+            .filter(|&&b| b != a)
+            .collect::<Vec<_>>()
+            .choose(&mut rng)
+            .unwrap();
+        numbers.push(a + b);
+    }
+
+    let (invalid, run) = loop {
+        let start = rng.gen_range(0..(numbers.len() - 1));
+        let end = rng.gen_range((start + 2)..=(start + 17).min(numbers.len()));
+        let sum = numbers[start..end].iter().sum();
+
+        if !is_valid(&numbers[(numbers.len() - PREAMBLE_SIZE)..], sum)
+            && runs_adding_up_to(&numbers, sum) == [(start, end)]
+        {
+            break (sum, (start, end));
+        }
+    };
+    numbers.push(invalid);
+
+    while numbers.len() < size {
+        numbers.push(rng.gen_range((invalid / 2 + 1)..=invalid));
+    }
+
+    (numbers, run)
+}
+
+// The start and end (exclusive) of every run of at least two numbers that adds up to sum
+fn runs_adding_up_to(numbers: &[usize], sum: usize) -> Vec<(usize, usize)> {
+    let mut runs = vec![];
+    for (start, &first) in numbers.iter().enumerate() {
+        let mut total = first;
+        for (end, &number) in numbers.iter().enumerate().skip(start + 1) {
+            total += number;
+            if total == sum {
+                runs.push((start, end + 1));
+            }
+            if total >= sum {
+                break;
+            }
+        }
+    }
+
+    runs
+}
+
+fn is_valid(window: &[usize], number: usize) -> bool {
+    window
+        .iter()
+        .any(|&a| window.iter().any(|&b| a != b && a + b == number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 100), generate(1, 100));
+        assert_ne!(generate(1, 100), generate(2, 100));
+
+        let numbers = Day09::parse(&generate(1, 100)).unwrap();
+        assert_eq!(numbers.len(), 100);
+        assert_eq!(Day09::part_1(&numbers), numbers[99]);
+        check_run(&numbers, xmas_numbers(1, 100).1, 99);
+
+        let numbers = Day09::parse(&generate(1, 2000)).unwrap();
+        let invalid_idx = PREAMBLE_SIZE + MAX_VALID;
+        assert_eq!(Day09::part_1(&numbers), numbers[invalid_idx]);
+        check_run(&numbers, xmas_numbers(1, 2000).1, invalid_idx);
+    }
+
+    // The planted run is the only one that adds up to the invalid number, so it's what part 2 finds
+    fn check_run(numbers: &[usize], (start, end): (usize, usize), invalid_idx: usize) {
+        let invalid = numbers[invalid_idx];
+        assert_eq!(runs_adding_up_to(numbers, invalid), [(start, end)]);
+
+        let run = &numbers[start..end];
+        let (min, max) = (run.iter().min().unwrap(), run.iter().max().unwrap());
+        assert_eq!(crate::part_2(numbers, PREAMBLE_SIZE), min + max);
+    }
+}
@@ -0,0 +1,79 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 100;
+
+// The number of ways to arrange a run of 1 to 5 consecutive adapters between two 3-jolt gaps
+const RUN_ARRANGEMENTS: [usize; 5] = [1, 1, 2, 4, 7];
+const MAX_ARRANGEMENTS: usize = 1 << 50;
+
+// size adapters, in runs of consecutive joltages separated by gaps of 3 jolts. The number of
+// arrangements is the product of each run's arrangements, so once that gets close to overflowing,
+// runs are kept short enough to only have one arrangement each.
+pub fn generate(seed: u64, size: usize) -> String {
+    let (adapters, _runs) = adapter_runs(seed, size);
+
+    adapters
+        .iter()
+        .map(|adapter| format!("{}\n", adapter))
+        .collect()
+}
+
+// The adapters, along with the length of each run
+fn adapter_runs(seed: u64, size: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut adapters = vec![];
+    let mut runs = vec![];
+    let mut joltage = 0;
+    let mut arrangements = 1;
+
+    while adapters.len() < size {
+        let mut run = rng.gen_range(1..=5).min(size - adapters.len());
+        if arrangements * RUN_ARRANGEMENTS[run - 1] > MAX_ARRANGEMENTS {
+            run = run.min(2);
+        }
+        arrangements *= RUN_ARRANGEMENTS[run - 1];
+        runs.push(run);
+
+        joltage += 3;
+        adapters.extend(joltage..(joltage + run));
+        joltage += run - 1;
+    }
+    adapters.shuffle(&mut rng);
+
+    (adapters, runs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        check_runs(50);
+        check_runs(2000);
+    }
+
+    // A run of n adapters has n - 1 gaps of 1 jolt inside it, and every run (as well as the
+    // device) comes after a gap of 3 jolts
+    fn check_runs(size: usize) {
+        let adapters = Day10::parse(&generate(1, size)).unwrap();
+        let (_, runs) = adapter_runs(1, size);
+        assert_eq!(adapters.len(), size);
+        assert_eq!(runs.iter().sum::<usize>(), size);
+
+        let arrangements: usize = runs.iter().map(|run| RUN_ARRANGEMENTS[run - 1]).product();
+        assert!(arrangements <= MAX_ARRANGEMENTS);
+        assert_eq!(
+            Day10::part_1(&adapters),
+            (size - runs.len()) * (runs.len() + 1)
+        );
+        assert_eq!(Day10::part_2(&adapters), arrangements);
+    }
+}
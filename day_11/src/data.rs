@@ -1,11 +1,11 @@
-use std::collections::BTreeMap;
+use aoc_common::grid::{Coord, Grid, ALL_DIRECTIONS};
+use aoc_common::ParseError;
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 use NeighborMode::*;
 use Spot::*;
 
-pub type Coord = (isize, isize);
-
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub enum Spot {
     EmptySeat,
@@ -13,7 +13,17 @@ pub enum Spot {
     Floor,
 }
 
-pub type Spots = BTreeMap<Coord, Spot>;
+impl Display for Spot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EmptySeat => write!(f, "L"),
+            TakenSeat => write!(f, "#"),
+            Floor => write!(f, "."),
+        }
+    }
+}
+
+pub type Spots = Grid<Spot>;
 
 #[derive(Eq, PartialEq)]
 pub enum NeighborMode {
@@ -29,37 +39,7 @@ pub struct SeatMap {
 impl SeatMap {
     pub fn fill_seats(&mut self, neighbor_mode: &NeighborMode, max_neighbors: usize) {
         loop {
-            let mut next_spots: Spots = BTreeMap::new();
-
-            for (coord, spot) in self.spots.iter() {
-                let next_spot = match spot {
-                    EmptySeat => {
-                        let adj = match neighbor_mode {
-                            Adjacent => self.adjacent_spots(&coord),
-                            LineOfSight => self.los_spots(&coord),
-                        };
-                        if !adj.iter().any(|s| **s == TakenSeat) {
-                            TakenSeat
-                        } else {
-                            EmptySeat
-                        }
-                    }
-                    TakenSeat => {
-                        let adj = match neighbor_mode {
-                            Adjacent => self.adjacent_spots(&coord),
-                            LineOfSight => self.los_spots(&coord),
-                        };
-                        if adj.iter().filter(|s| ***s == TakenSeat).count() >= max_neighbors {
-                            EmptySeat
-                        } else {
-                            TakenSeat
-                        }
-                    }
-                    Floor => Floor,
-                };
-
-                next_spots.insert(coord.clone(), next_spot);
-            }
+            let next_spots = self.next_spots(neighbor_mode, max_neighbors);
 
             if self.spots == next_spots {
                 break;
@@ -69,53 +49,45 @@ impl SeatMap {
         }
     }
 
-    pub fn adjacent_spots(&self, coord: &Coord) -> Vec<&Spot> {
-        let mut spots = Vec::with_capacity(8);
-
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dy == 0 && dx == 0 {
-                    continue;
+    // Where everyone sits after one round of people moving
+    pub fn next_spots(&self, neighbor_mode: &NeighborMode, max_neighbors: usize) -> Spots {
+        self.spots.map(|coord, spot| match spot {
+            EmptySeat => {
+                let adj = match neighbor_mode {
+                    Adjacent => self.adjacent_spots(&coord),
+                    LineOfSight => self.los_spots(&coord),
+                };
+                if !adj.iter().any(|s| **s == TakenSeat) {
+                    TakenSeat
+                } else {
+                    EmptySeat
                 }
-
-                if let Some(s) = self.spots.get(&(coord.0 + dy, coord.1 + dx)) {
-                    spots.push(s)
+            }
+            TakenSeat => {
+                let adj = match neighbor_mode {
+                    Adjacent => self.adjacent_spots(&coord),
+                    LineOfSight => self.los_spots(&coord),
+                };
+                if adj.iter().filter(|s| ***s == TakenSeat).count() >= max_neighbors {
+                    EmptySeat
+                } else {
+                    TakenSeat
                 }
             }
-        }
+            Floor => Floor,
+        })
+    }
 
-        spots
+    pub fn adjacent_spots(&self, coord: &Coord) -> Vec<&Spot> {
+        self.spots.neighbors_8(*coord).collect()
     }
 
+    // The first seat that can be seen in each direction, looking past any floor
     pub fn los_spots(&self, coord: &Coord) -> Vec<&Spot> {
-        let mut spots = Vec::new();
-
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dy == 0 && dx == 0 {
-                    continue;
-                }
-
-                let mut my = 1;
-                let mut mx = 1;
-
-                loop {
-                    let los_coord = (coord.0 + (dy * my), coord.1 + (dx * mx));
-                    if let Some(s) = self.spots.get(&los_coord) {
-                        if *s != Floor {
-                            spots.push(s);
-                            break;
-                        }
-                        my += 1;
-                        mx += 1;
-                    } else {
-                        break;
-                    }
-                }
-            }
-        }
-
-        spots
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(|&direction| self.spots.ray(*coord, direction).find(|s| **s != Floor))
+            .collect()
     }
 
     pub fn occupied_seats(&self) -> usize {
@@ -124,38 +96,50 @@ impl SeatMap {
 }
 
 impl FromStr for SeatMap {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut spots = Spots::new();
-
-        for (y, line) in s.split("\n").enumerate() {
-            for (x, char) in line.chars().enumerate() {
-                let spot = match char {
-                    'L' => EmptySeat,
-                    '.' => Floor,
-                    '#' => TakenSeat,
-                    _ => panic!("Unknown map character: {}", char),
-                };
-                spots.insert((y as isize, x as isize), spot);
-            }
-        }
+        let spots = Grid::parse(s, |char| match char {
+            'L' => Some(EmptySeat),
+            '.' => Some(Floor),
+            '#' => Some(TakenSeat),
+            _ => None,
+        })?;
 
         Ok(SeatMap { spots })
     }
 }
 
+impl Display for SeatMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.spots)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_seat_map_from_str() {
         let seat_map: SeatMap = crate::tests::example_input().parse().unwrap();
-        assert_eq!(seat_map.spots.get(&(0, 0)), Some(&EmptySeat));
-        assert_eq!(seat_map.spots.get(&(0, 1)), Some(&Floor));
-        assert_eq!(seat_map.spots.get(&(0, 2)), Some(&EmptySeat));
-        assert_eq!(seat_map.spots.get(&(0, 3)), Some(&EmptySeat));
-        assert_eq!(seat_map.spots.get(&(0, 4)), Some(&Floor));
+        assert_eq!(seat_map.spots.get((0, 0)), Some(&EmptySeat));
+        assert_eq!(seat_map.spots.get((1, 0)), Some(&Floor));
+        assert_eq!(seat_map.spots.get((2, 0)), Some(&EmptySeat));
+        assert_eq!(seat_map.spots.get((3, 0)), Some(&EmptySeat));
+        assert_eq!(seat_map.spots.get((4, 0)), Some(&Floor));
+        assert_eq!(seat_map.to_string(), crate::tests::example_input().trim());
+    }
+
+    proptest! {
+        #[test]
+        fn test_seat_map_round_trip(rows in (1..40_usize).prop_flat_map(|width| {
+            let row = prop::string::string_regex(&format!("[L.#]{{{}}}", width)).unwrap();
+            prop::collection::vec(row, 1..40)
+        })) {
+            let s = rows.join("\n");
+            prop_assert_eq!(s.parse::<SeatMap>().unwrap().to_string(), s);
+        }
     }
 }
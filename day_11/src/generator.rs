@@ -0,0 +1,100 @@
+use crate::data::{NeighborMode, SeatMap, Spot};
+use aoc_common::grid::{Coord, Grid};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 95;
+
+// A square waiting area, size spots along each side, where about 80% of the spots are (empty)
+// seats and the rest are floor.
+//
+// Unlike the real inputs, random layouts often never settle: people end up flipping between two
+// arrangements forever. Any seats that are still flipping once everyone else has settled get
+// turned into floor, until the seating settles under both parts' rules.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let spots = (0..(size * size))
+        .map(|_| {
+            if rng.gen_bool(0.8) {
+                Spot::EmptySeat
+            } else {
+                Spot::Floor
+            }
+        })
+        .collect();
+    let mut seat_map = SeatMap {
+        spots: Grid::new(size, size, spots),
+    };
+
+    loop {
+        let mut flipping = flipping_seats(&seat_map, &NeighborMode::Adjacent, 4);
+        flipping.extend(flipping_seats(&seat_map, &NeighborMode::LineOfSight, 5));
+        if flipping.is_empty() {
+            break;
+        }
+
+        for coord in flipping {
+            seat_map.spots.set(coord, Spot::Floor);
+        }
+    }
+
+    format!("{}\n", seat_map)
+}
+
+// Fills the seats until they either settle (so that nothing is flipping), or until they start
+// alternating between two arrangements
+fn flipping_seats(
+    seat_map: &SeatMap,
+    neighbor_mode: &NeighborMode,
+    max_neighbors: usize,
+) -> Vec<Coord> {
+    let mut seat_map = seat_map.clone();
+
+    loop {
+        let next = SeatMap {
+            spots: seat_map.next_spots(neighbor_mode, max_neighbors),
+        };
+        if next.spots == seat_map.spots {
+            return vec![];
+        }
+
+        if next.next_spots(neighbor_mode, max_neighbors) == seat_map.spots {
+            return seat_map
+                .spots
+                .iter()
+                .filter(|(coord, spot)| next.spots.get(*coord) != Some(spot))
+                .map(|(coord, _)| coord)
+                .collect();
+        }
+
+        seat_map = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 20), generate(1, 20));
+        assert_ne!(generate(1, 20), generate(2, 20));
+
+        let seat_map = Day11::parse(&generate(1, 20)).unwrap();
+        assert_eq!((seat_map.spots.width(), seat_map.spots.height()), (20, 20));
+
+        // The seating settles under both parts' rules
+        assert_eq!(
+            flipping_seats(&seat_map, &NeighborMode::Adjacent, 4),
+            vec![]
+        );
+        assert_eq!(
+            flipping_seats(&seat_map, &NeighborMode::LineOfSight, 5),
+            vec![]
+        );
+        assert!(Day11::part_1(&seat_map) > 0);
+        assert!(Day11::part_2(&seat_map) > 0);
+    }
+}
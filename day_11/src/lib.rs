@@ -0,0 +1,68 @@
+pub mod data;
+pub mod generator;
+
+use crate::data::{NeighborMode, SeatMap};
+use aoc_common::{ParseError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = SeatMap;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<SeatMap, ParseError> {
+        input.trim_end().parse()
+    }
+
+    fn part_1(seat_map: &SeatMap) -> usize {
+        part_1(seat_map.clone())
+    }
+
+    fn part_2(seat_map: &SeatMap) -> usize {
+        part_2(seat_map.clone())
+    }
+}
+
+// How many seats end up occupied?
+pub fn part_1(mut seat_map: SeatMap) -> usize {
+    seat_map.fill_seats(&NeighborMode::Adjacent, 4);
+    seat_map.occupied_seats()
+}
+
+pub fn part_2(mut seat_map: SeatMap) -> usize {
+    seat_map.fill_seats(&NeighborMode::LineOfSight, 5);
+    seat_map.occupied_seats()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn example_input() -> &'static str {
+        indoc::indoc! {"
+            L.LL.LL.LL
+            LLLLLLL.LL
+            L.L.L..L..
+            LLLL.LL.LL
+            L.LL.LL.LL
+            L.LLLLL.LL
+            ..L.L.....
+            LLLLLLLLLL
+            L.LLLLLL.L
+            L.LLLLL.LL
+        "}
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(example_input().parse().unwrap()), 37);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(example_input().parse().unwrap()), 26);
+    }
+}
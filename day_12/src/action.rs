@@ -0,0 +1,101 @@
+use aoc_common::{parse_number, ParseError};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+// One line of the navigation instructions, like "F10" or "R90"
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Action {
+    pub kind: char,
+    pub value: isize,
+}
+
+impl FromStr for Action {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = s
+            .chars()
+            .next()
+            .ok_or_else(|| ParseError::in_line(s, s, "Expected an action"))?;
+        let value_str = &s[kind.len_utf8()..];
+
+        if !"NSEWLRF".contains(kind) {
+            return Err(ParseError::in_line(
+                s,
+                &s[..kind.len_utf8()],
+                "Unknown action",
+            ));
+        }
+
+        let value = parse_number(value_str).map_err(|e| e.within(s, value_str))?;
+
+        // Both ships can only turn in right angles
+        if "LR".contains(kind) && ![90, 180, 270].contains(&value) {
+            return Err(ParseError::in_line(
+                s,
+                value_str,
+                "Expected 90, 180 or 270 degrees",
+            ));
+        }
+
+        Ok(Action { kind, value })
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.kind, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Moves can be any distance, but turns have to be right angles
+    fn action() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            (
+                prop::sample::select(vec!['N', 'S', 'E', 'W', 'F']),
+                any::<isize>()
+            ),
+            (
+                prop::sample::select(vec!['L', 'R']),
+                prop::sample::select(vec![90, 180, 270])
+            ),
+        ]
+        .prop_map(|(kind, value)| Action { kind, value })
+    }
+
+    #[test]
+    fn test_action_from_str() {
+        assert_eq!(
+            "F10".parse(),
+            Ok(Action {
+                kind: 'F',
+                value: 10
+            })
+        );
+        assert_eq!(
+            "R270".parse(),
+            Ok(Action {
+                kind: 'R',
+                value: 270
+            })
+        );
+        assert_eq!(
+            "X10".parse::<Action>(),
+            Err(ParseError::new(1, 1, "X", "Unknown action"))
+        );
+        assert_eq!("L45".parse::<Action>().unwrap_err().column, 2);
+        assert_eq!("N".parse::<Action>().unwrap_err().column, 2);
+    }
+
+    proptest! {
+        #[test]
+        fn test_action_round_trip(action in action()) {
+            prop_assert_eq!(action.to_string().parse(), Ok(action));
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 780;
+
+// size navigation instructions, where turns are always right angles and everything else moves
+// between 1 and 100 units
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            let kind = *['N', 'S', 'E', 'W', 'L', 'R', 'F']
+                .choose(&mut rng)
+                .unwrap();
+            let value = match kind {
+                'L' | 'R' => *[90, 180, 270].choose(&mut rng).unwrap(),
+                _ => rng.gen_range(1..=100),
+            };
+
+            format!("{}{}\n", kind, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let actions = Day12::parse(&generate(1, 50)).unwrap();
+        assert_eq!(actions.len(), 50);
+        for action in &actions {
+            match action.kind {
+                'L' | 'R' => assert!([90, 180, 270].contains(&action.value)),
+                _ => assert!((1..=100).contains(&action.value)),
+            }
+        }
+
+        // The ship can't end up further away than the distance it moved
+        let moved: isize = actions
+            .iter()
+            .filter(|action| !"LR".contains(action.kind))
+            .map(|action| action.value)
+            .sum();
+        assert!(Day12::part_1(&actions) <= moved);
+        Day12::part_2(&actions);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::action::Action;
+use aoc_common::{parse_lines, ParseError, Solution};
+
+pub mod action;
+pub mod generator;
+pub mod part_1_ship;
+pub mod part_2_ship;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<Action>;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Result<Vec<Action>, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_1(instructions: &Vec<Action>) -> isize {
+        part_1(instructions)
+    }
+
+    fn part_2(instructions: &Vec<Action>) -> isize {
+        part_2(instructions)
+    }
+}
+
+pub fn part_1(instructions: &[Action]) -> isize {
+    let mut ship = part_1_ship::Ship {
+        x: 0,
+        y: 0,
+        facing: 90,
+    };
+    instructions.iter().for_each(|i| ship.do_action(i));
+    ship.x.abs() + ship.y.abs()
+}
+
+pub fn part_2(instructions: &[Action]) -> isize {
+    let mut ship = part_2_ship::Ship {
+        x: 0,
+        y: 0,
+        wpt_dx: 10,
+        wpt_dy: 1,
+    };
+    instructions.iter().for_each(|i| ship.do_action(i));
+    ship.x.abs() + ship.y.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<Action> {
+        Day12::parse("F10\nN3\nF7\nR90\nF11").unwrap()
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&example()), 25);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&example()), 286);
+    }
+}
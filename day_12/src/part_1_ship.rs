@@ -1,3 +1,5 @@
+use crate::action::Action;
+
 #[derive(Debug)]
 pub struct Ship {
     pub x: isize,
@@ -6,12 +8,10 @@ pub struct Ship {
 }
 
 impl Ship {
-    pub fn do_action(&mut self, action_str: &String) {
-        let mut chars = action_str.chars();
-        let action = chars.nth(0).unwrap();
-        let value: isize = chars.as_str().parse().unwrap();
+    pub fn do_action(&mut self, action: &Action) {
+        let value = action.value;
 
-        match action {
+        match action.kind {
             // Move in a this direction for some distance
             'N' => self.y += value,
             'S' => self.y -= value,
@@ -32,7 +32,7 @@ impl Ship {
             },
 
             // Whoops
-            _ => panic!("Unknown action: {}", action),
+            _ => panic!("Unknown action: {}", action.kind),
         }
 
         // Normalize the direction we're facing
@@ -1,3 +1,5 @@
+use crate::action::Action;
+
 #[derive(Debug)]
 pub struct Ship {
     pub x: isize,
@@ -7,12 +9,10 @@ pub struct Ship {
 }
 
 impl Ship {
-    pub fn do_action(&mut self, action_str: &String) {
-        let mut chars = action_str.chars();
-        let action = chars.nth(0).unwrap();
-        let value: isize = chars.as_str().parse().unwrap();
+    pub fn do_action(&mut self, action: &Action) {
+        let value = action.value;
 
-        match action {
+        match action.kind {
             // Move the waypoint this direction for some distance
             'N' => self.wpt_dy += value,
             'S' => self.wpt_dy -= value,
@@ -20,7 +20,7 @@ impl Ship {
             'W' => self.wpt_dx -= value,
 
             // Rotate the waypoint around the ship this direction for some degrees
-            'L' | 'R' => match (action, value) {
+            'L' | 'R' => match (action.kind, value) {
                 ('L', 90) | ('R', 270) => {
                     let new_wpt = r90(r90(r90((self.wpt_dx, self.wpt_dy))));
                     self.wpt_dx = new_wpt.0;
@@ -35,7 +35,7 @@ impl Ship {
                     self.wpt_dx *= -1;
                     self.wpt_dy *= -1;
                 }
-                _ => panic!("Unsupported turn command: {}", action_str),
+                _ => panic!("Unsupported turn command: {:?}", action),
             },
 
             // Go to the waypoint the given number of times (waypoint is always relative to ship)
@@ -45,7 +45,7 @@ impl Ship {
             }
 
             // Whoops
-            _ => panic!("Unknown action: {}", action),
+            _ => panic!("Unknown action: {}", action.kind),
         }
     }
 }
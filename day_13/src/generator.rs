@@ -0,0 +1,88 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 62;
+
+const MAX_BUSES: usize = 9;
+const MAX_ID_PRODUCT: usize = 1_000_000_000_000_000;
+
+// Notes with size slots in the bus schedule (at least 1), of which up to 9 are buses. The first
+// slot is always a bus. Bus IDs are distinct primes, so that part 2 always has a solution, and
+// their product (which the answer is below) is kept under 10^15.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let mut primes: Vec<usize> = (7..1000).filter(|&n| is_prime(n)).collect();
+    primes.shuffle(&mut rng);
+
+    let bus_count = rng.gen_range(2..=MAX_BUSES).min(size);
+    let mut product = 1;
+    let mut bus_ids = vec![];
+    for prime in primes {
+        if bus_ids.len() < bus_count && product * prime < MAX_ID_PRODUCT {
+            product *= prime;
+            bus_ids.push(prime);
+        }
+    }
+
+    let mut offsets: Vec<usize> = (1..size).collect();
+    offsets.shuffle(&mut rng);
+
+    let mut slots = vec!["x".to_string(); size];
+    for (offset, bus_id) in [0].iter().chain(&offsets).zip(bus_ids) {
+        slots[*offset] = bus_id.to_string();
+    }
+
+    format!(
+        "{}\n{}\n",
+        rng.gen_range(100_000..=1_000_000),
+        slots.join(",")
+    )
+}
+
+fn is_prime(n: usize) -> bool {
+    (2..n)
+        .take_while(|d| d * d <= n)
+        .all(|d| !n.is_multiple_of(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let notes = Day13::parse(&generate(1, 50)).unwrap();
+        assert_eq!(notes[1].split(',').count(), 50);
+        assert_ne!(notes[1].split(',').next(), Some("x"));
+        Day13::part_1(&notes);
+
+        // Up to 9 buses with distinct prime ids, and every bus leaves at its offset from the answer
+        let buses: Vec<(usize, usize)> = notes[1]
+            .split(',')
+            .enumerate()
+            .filter(|(_, slot)| *slot != "x")
+            .map(|(offset, id)| (offset, id.parse().unwrap()))
+            .collect();
+        assert!((2..=MAX_BUSES).contains(&buses.len()));
+        assert!(buses.iter().all(|&(_, id)| is_prime(id)));
+        let ids: Vec<usize> = buses.iter().map(|&(_, id)| id).collect();
+        assert!(ids
+            .iter()
+            .all(|id| ids.iter().filter(|&other| other == id).count() == 1));
+        assert!(ids.iter().product::<usize>() < MAX_ID_PRODUCT);
+
+        let timestamp = Day13::part_2(&notes);
+        assert!(timestamp < MAX_ID_PRODUCT);
+        for (offset, id) in buses {
+            assert_eq!((timestamp + offset) % id, 0);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use aoc_common::{parse_number, ParseError, Solution};
+
+pub mod generator;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part_1(lines: &Vec<String>) -> usize {
+        part_1(lines)
+    }
+
+    fn part_2(lines: &Vec<String>) -> usize {
+        part_2(lines)
+    }
+}
+
+// What is the ID of the earliest bus you can take to the airport multiplied by the number of
+// minutes you'll need to wait for that bus?
+pub fn part_1(lines: &[String]) -> usize {
+    // Parse the two lines, throwing away "x" values with #filter_map
+    let earliest_time: usize = lines[0].parse().unwrap();
+    let bus_ids: Vec<usize> = lines[1]
+        .split(',')
+        .filter_map(|id| id.parse().ok())
+        .collect();
+
+    let mut current_time = earliest_time;
+
+    loop {
+        for id in bus_ids.iter() {
+            // Return the first id that divides evenly into the current time
+            if current_time.is_multiple_of(*id) {
+                return id * (current_time - earliest_time);
+            }
+        }
+
+        current_time += 1;
+    }
+}
+
+pub type BusIds = Vec<(usize, usize)>;
+
+// What is the earliest timestamp such that all of the listed bus IDs depart at offsets matching
+// their positions in the list?
+pub fn part_2(lines: &[String]) -> usize {
+    // (offset, bus_id)
+    let mut bus_ids: BusIds = lines[1]
+        .split(',')
+        .enumerate()
+        .filter_map(|(idx, id)| Some(idx).zip(id.parse().ok()))
+        .collect();
+
+    let mut current_time = 0;
+
+    while bus_ids.len() > 1 {
+        let (new_bus_ids, new_current_time) = reduce_problem(&bus_ids, current_time);
+        bus_ids = new_bus_ids;
+        current_time = new_current_time;
+    }
+
+    current_time
+}
+
+// Returns a new bus_ids and new current_time. Essentially, I noticed with busses [7, 13] that
+// the first time they'd "align" 1 minute apart was at t=77, then at t=168, then at t=259, then at
+// t=350, etc. I don't know why t=77 is the first one, but I noticed that each subsequent alignment
+// is 91 minutes apart. So, that allows us to reduce our problem to just one bus that comes every
+// 91 minutes (aka bus id #91)! Note that this "new" bus is only valid for t>=77, so we return that
+// new current_time as well.
+pub fn reduce_problem(bus_ids: &[(usize, usize)], mut current_time: usize) -> (BusIds, usize) {
+    let mut bus_ids = bus_ids.to_vec();
+
+    let a_idx = 0;
+    let b_idx = 1;
+    let ab_offset = bus_ids[b_idx].0 - bus_ids[a_idx].0;
+    let a_id = bus_ids[a_idx].1;
+    let b_id = bus_ids[b_idx].1;
+
+    // Keep track of the first two times we get a solution
+    let mut solved_times = vec![];
+
+    loop {
+        let b_time = current_time + ab_offset;
+
+        // If a "B" happens in "ab_offset" seconds, we found an "alignment" and can record it
+        if b_time.is_multiple_of(b_id) {
+            // println!("\n###");
+            // println!("t={} a={}", current_time, current_time % a_id);
+            // println!("(t+{})={} b={}", ab_offset, b_time, b_time % b_id);
+            solved_times.push(current_time);
+        }
+
+        if solved_times.len() == 5 {
+            break;
+        }
+
+        // Advance to the next occurrence of "A"
+        current_time += a_id;
+    }
+
+    // Replace our A + B busses with a new reduced bus
+    let new_bus_id = solved_times[1] - solved_times[0];
+    bus_ids.remove(0);
+    bus_ids.remove(0);
+    bus_ids.insert(0, (0, new_bus_id));
+
+    // Return the reduced bus IDs, and our new current_time
+    (bus_ids, solved_times[0])
+}
+
+// (starting_current_time, new_problem)
+
+// The parts re-parse the two lines as they go, so check up front that they're well-formed: the
+// earliest departure time, then a comma-separated list of bus IDs (with "x" for out of service)
+pub fn parse_notes(input: &str) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<&str> = input.trim_end().lines().collect();
+
+    if let [earliest_time, bus_ids] = lines.as_slice() {
+        parse_number::<usize>(earliest_time)?;
+
+        if bus_ids.split(',').all(|id| id == "x") {
+            return Err(
+                ParseError::in_line(bus_ids, bus_ids, "Expected at least one bus ID")
+                    .offset_lines(1),
+            );
+        }
+        for id in bus_ids.split(',').filter(|&id| id != "x") {
+            if parse_number::<usize>(id).map_err(|e| e.within(bus_ids, id).offset_lines(1))? == 0 {
+                return Err(
+                    ParseError::in_line(bus_ids, id, "Bus IDs must be positive").offset_lines(1)
+                );
+            }
+        }
+
+        Ok(lines.iter().map(|s| s.to_string()).collect())
+    } else {
+        Err(ParseError::new(
+            1,
+            1,
+            "",
+            format!("Expected 2 lines, not {}", lines.len()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn test_input() -> Vec<String> {
+        vec!["939".into(), "7,13,x,x,59,x,31,19".into()]
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&test_input()), 295);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&["".into(), "7,13,x,x,59,x,31,19".into()]), 1068781);
+        assert_eq!(part_2(&["".into(), "17,x,13,19".into()]), 3417);
+        assert_eq!(part_2(&["".into(), "67,7,59,61".into()]), 754018);
+        assert_eq!(part_2(&["".into(), "67,x,7,59,61".into()]), 779210);
+        assert_eq!(part_2(&["".into(), "67,7,x,59,61".into()]), 1261476);
+        assert_eq!(part_2(&["".into(), "1789,37,47,1889".into()]), 1202161486);
+    }
+
+    #[test]
+    fn test_parse_notes() {
+        assert_eq!(parse_notes("939\n7,13,x,x,59,x,31,19\n"), Ok(test_input()));
+        assert_eq!(
+            parse_notes("939\n7,13,x,y,59"),
+            Err(ParseError::new(
+                2,
+                8,
+                "y",
+                "Invalid number: invalid digit found in string"
+            ))
+        );
+        assert_eq!(parse_notes("939\n7,0").unwrap_err().column, 3);
+        assert_eq!(
+            parse_notes("939\nx,x").unwrap_err(),
+            ParseError::new(2, 1, "x,x", "Expected at least one bus ID")
+        );
+        assert_eq!(parse_notes("939").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_reduce_problem() {
+        assert_eq!(
+            reduce_problem(&[(0, 7), (1, 13), (3, 2)], 0),
+            (vec![(0, 91), (3, 2)], 77)
+        );
+    }
+}
@@ -1,4 +1,6 @@
+use aoc_common::{parse_number, ParseError};
 use std::collections::HashMap;
+use std::fmt::{self, Display};
 use std::str::FromStr;
 
 use crate::mask::Mask;
@@ -13,39 +15,56 @@ pub enum Instruction {
     WriteMemory { index: usize, value: usize },
 }
 
+// Values and addresses are 36-bit unsigned integers
+const MAX_VALUE: usize = (1 << 36) - 1;
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetMask(mask) => write!(f, "mask = {}", mask.0),
+            WriteMemory { index, value } => write!(f, "mem[{}] = {}", index, value),
+        }
+    }
+}
+
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match &s[0..=1] {
-            "ma" => {
-                let mask: Mask = Mask(s[7..].to_string());
-                Ok(SetMask(mask))
+        if let Some(mask) = s.strip_prefix("mask = ") {
+            if mask.chars().count() != 36 {
+                return Err(ParseError::in_line(s, mask, "Expected 36 characters"));
             }
-            "me" => {
-                let left_bracket_idx = s.find('[').unwrap();
-                let right_bracket_idx = s.find(']').unwrap();
-                let equals_idx = s.find('=').unwrap();
-                let index = s[(left_bracket_idx + 1)..right_bracket_idx]
-                    .parse()
-                    .unwrap();
-                let value = s[(equals_idx + 2)..].parse().unwrap();
-                Ok(WriteMemory { index, value })
+            if let Some(bad_char) = mask.matches(|c| !"01X".contains(c)).next() {
+                return Err(ParseError::in_line(s, bad_char, "Expected '0', '1' or 'X'"));
             }
-            _ => panic!("Unsupported instruction: {}", s),
+
+            Ok(SetMask(Mask(mask.to_string())))
+        } else if let Some(rest) = s.strip_prefix("mem[") {
+            let (index_str, value_str) = rest
+                .split_once("] = ")
+                .ok_or_else(|| ParseError::in_line(s, rest, "Expected '<index>] = <value>'"))?;
+            let number = |part: &str| -> Result<usize, ParseError> {
+                match parse_number(part).map_err(|e| e.within(s, part))? {
+                    n if n > MAX_VALUE => Err(ParseError::in_line(s, part, "Expected 36 bits")),
+                    n => Ok(n),
+                }
+            };
+
+            Ok(WriteMemory {
+                index: number(index_str)?,
+                value: number(value_str)?,
+            })
+        } else {
+            Err(ParseError::in_line(s, s, "Unknown instruction"))
         }
     }
 }
 
-#[derive(Eq, PartialEq, Debug)]
-pub struct Program {
-    pub instructions: Instructions,
-    pub memory: Memory,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_instruction_from_str() {
@@ -66,5 +85,37 @@ mod tests {
                 value: 11
             }
         );
+
+        assert_eq!(
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X".parse::<Instruction>(),
+            Err(ParseError::new(1, 42, "2", "Expected '0', '1' or 'X'"))
+        );
+        assert_eq!(
+            "mask = XXX".parse::<Instruction>().unwrap_err().reason,
+            "Expected 36 characters"
+        );
+        assert_eq!("mem[8] = x".parse::<Instruction>().unwrap_err().column, 10);
+        assert_eq!(
+            "mem[68719476736] = 1"
+                .parse::<Instruction>()
+                .unwrap_err()
+                .column,
+            5
+        );
+        assert_eq!("mem 8 = 11".parse::<Instruction>().unwrap_err().column, 1);
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            "[01X]{36}".prop_map(|mask| SetMask(Mask(mask))),
+            (0..=MAX_VALUE, 0..=MAX_VALUE).prop_map(|(index, value)| WriteMemory { index, value }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_instruction_round_trip(instruction in instruction()) {
+            prop_assert_eq!(instruction.to_string().parse(), Ok(instruction));
+        }
     }
 }
@@ -0,0 +1,80 @@
+use crate::data::Instruction::{SetMask, WriteMemory};
+use crate::mask::Mask;
+use rand::rngs::StdRng;
+use rand::seq::index;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 580;
+
+const MAX_FLOATING: usize = 9;
+
+// A program of size instructions: a mask, followed by a few writes to memory, over and over. Masks
+// have at most 9 floating bits, since part 2 writes to 2^n addresses for a mask with n of them.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut program = String::new();
+    let mut remaining = size;
+
+    while remaining > 0 {
+        let writes = rng.gen_range(1..=6).min(remaining - 1);
+        remaining -= writes + 1;
+
+        program.push_str(&format!("{}\n", SetMask(mask(&mut rng))));
+        for _ in 0..writes {
+            let write = WriteMemory {
+                index: rng.gen_range(0..65536),
+                value: rng.gen_range(0..(1 << 30)),
+            };
+            program.push_str(&format!("{}\n", write));
+        }
+    }
+
+    program
+}
+
+fn mask(rng: &mut StdRng) -> Mask {
+    let mut bits: Vec<char> = (0..36)
+        .map(|_| if rng.gen_bool(0.5) { '1' } else { '0' })
+        .collect();
+
+    let floating = rng.gen_range(0..=MAX_FLOATING);
+    for idx in index::sample(rng, bits.len(), floating) {
+        bits[idx] = 'X';
+    }
+
+    Mask(bits.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let instructions = Day14::parse(&generate(1, 50)).unwrap();
+        assert_eq!(instructions.len(), 50);
+
+        // Each mask has at most 9 floating bits and is followed by up to 6 writes
+        assert!(matches!(instructions[0], SetMask(_)));
+        let mut writes = 0;
+        for instruction in &instructions {
+            match instruction {
+                SetMask(Mask(bits)) => {
+                    assert!(bits.matches('X').count() <= MAX_FLOATING);
+                    writes = 0;
+                }
+                WriteMemory { .. } => {
+                    writes += 1;
+                    assert!(writes <= 6);
+                }
+            }
+        }
+        assert!(Day14::part_1(&instructions) > 0);
+        assert!(Day14::part_2(&instructions) > 0);
+    }
+}
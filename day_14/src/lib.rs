@@ -0,0 +1,103 @@
+use crate::data::{Instruction, Instructions, Memory};
+use aoc_common::{parse_lines, ParseError, Solution};
+
+pub mod data;
+pub mod generator;
+pub mod mask;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Instructions;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Instructions, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_1(instructions: &Instructions) -> usize {
+        part_1(instructions)
+    }
+
+    fn part_2(instructions: &Instructions) -> usize {
+        part_2(instructions)
+    }
+}
+
+// What is the sum of all values left in memory after it completes?
+pub fn part_1(instructions: &[Instruction]) -> usize {
+    let mut memory = Memory::new();
+    let mut current_mask = None;
+
+    for i in instructions {
+        match i {
+            Instruction::SetMask(mask) => current_mask = Some(mask),
+            Instruction::WriteMemory { index, value } => {
+                let masked_value = current_mask.unwrap().apply(*value);
+                memory.insert(*index, masked_value);
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+pub fn part_2(instructions: &[Instruction]) -> usize {
+    let mut memory = Memory::new();
+    let mut current_mask = None;
+
+    for i in instructions {
+        match i {
+            Instruction::SetMask(mask) => current_mask = Some(mask),
+            Instruction::WriteMemory { index, value } => {
+                for mem in current_mask.unwrap().all_floating_addresses(*index) {
+                    memory.insert(mem, *value);
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn example_input_1() -> Instructions {
+        [
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X",
+            "mem[8] = 11",
+            "mem[7] = 101",
+            "mem[8] = 0",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect()
+    }
+
+    pub fn example_input_2() -> Instructions {
+        [
+            "mask = 000000000000000000000000000000X1001X",
+            "mem[42] = 100",
+            "mask = 00000000000000000000000000000000X0XX",
+            "mem[26] = 1",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&example_input_1()), 165);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&example_input_2()), 208);
+    }
+}
@@ -15,10 +15,10 @@ impl Mask {
                 'X' => value[idx],
                 _ => panic!(),
             };
-            result.push(c.into());
+            result.push(c);
         }
 
-        usize::from_str_radix(&result, 2).unwrap() as usize
+        usize::from_str_radix(&result, 2).unwrap()
     }
 
     pub fn all_floating_addresses(&self, orig_address: usize) -> Vec<usize> {
@@ -56,7 +56,7 @@ impl Mask {
                 addr[idx] = sub_with;
             }
 
-            addresses.push(usize::from_str_radix(&String::from_iter(addr), 2).unwrap() as usize);
+            addresses.push(usize::from_str_radix(&String::from_iter(addr), 2).unwrap());
         }
 
         addresses
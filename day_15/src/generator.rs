@@ -0,0 +1,45 @@
+use rand::rngs::StdRng;
+use rand::seq::index;
+use rand::SeedableRng;
+
+pub const DEFAULT_SIZE: usize = 7;
+
+// size distinct starting numbers (at least 1), each below 3 times size. The work in this puzzle
+// comes from the number of turns rather than from the input, so size mostly changes how long the
+// game takes to settle down.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let numbers: Vec<String> = index::sample(&mut rng, size * 3, size)
+        .into_iter()
+        .map(|number| number.to_string())
+        .collect();
+
+    format!("{}\n", numbers.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day15;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let starting_numbers = Day15::parse(&generate(1, 50)).unwrap();
+        assert_eq!(starting_numbers.len(), 50);
+        assert!(starting_numbers.iter().all(|&number| number < 150));
+        let mut distinct = starting_numbers.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 50);
+
+        // Every number spoken after the starting ones is how many turns ago it was last spoken, so
+        // none of them can be more than the number of turns
+        assert!(Day15::part_1(&starting_numbers) < 2020);
+    }
+}
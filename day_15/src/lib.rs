@@ -0,0 +1,97 @@
+use aoc_common::{parse_number, ParseError, Solution};
+use std::collections::HashMap;
+
+pub mod generator;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<usize>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        let line = input.trim_end();
+        line.split(',')
+            .map(|n| parse_number(n).map_err(|e| e.within(line, n)))
+            .collect()
+    }
+
+    fn part_1(starting_numbers: &Vec<usize>) -> usize {
+        part_1(starting_numbers, 2020)
+    }
+
+    fn part_2(starting_numbers: &Vec<usize>) -> usize {
+        part_2(starting_numbers, 30000000)
+    }
+}
+
+// After the starting numbers, each turn results in that player speaking aloud either 0 (if the
+// last number is new) or an age (if the last number is a repeat).
+pub fn part_1(starting_numbers: &[usize], nth_number: usize) -> usize {
+    // <the number, previously spoken>
+    let mut turn = 1;
+    let mut history: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for n in starting_numbers {
+        history.entry(*n).or_insert(vec![turn]);
+        turn += 1;
+    }
+
+    let mut prev_num = *starting_numbers.last().unwrap();
+    let mut prev_num_was_new = true;
+
+    while turn <= nth_number {
+        let this_num = match prev_num_was_new {
+            true => 0,
+            false => {
+                let last_turn = turn - 1;
+                let num_history = history.get(&prev_num).unwrap();
+                let turn_num_prev_spoken = num_history[num_history.len() - 2];
+                last_turn - turn_num_prev_spoken
+            }
+        };
+
+        // Prepare for next turn
+        prev_num = this_num;
+        prev_num_was_new = !history.contains_key(&this_num);
+        history.entry(this_num).or_insert(vec![]).push(turn);
+        turn += 1;
+    }
+
+    prev_num
+}
+
+// I guess Rust is fast enough that I didn't have to optimize beyond what I already did...
+pub fn part_2(starting_numbers: &[usize], nth_number: usize) -> usize {
+    part_1(starting_numbers, nth_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(&[0, 3, 6], 2020), 436);
+        assert_eq!(part_1(&[1, 3, 2], 2020), 1);
+        assert_eq!(part_1(&[2, 1, 3], 2020), 10);
+        assert_eq!(part_1(&[1, 2, 3], 2020), 27);
+        assert_eq!(part_1(&[2, 3, 1], 2020), 78);
+        assert_eq!(part_1(&[3, 2, 1], 2020), 438);
+        assert_eq!(part_1(&[3, 1, 2], 2020), 1836);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Day15::parse("0,3,6\n"), Ok(vec![0, 3, 6]));
+        assert_eq!(Day15::parse("0,3,,6").unwrap_err().column, 5);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(&[0, 3, 6], 30_000_000), 175594);
+    }
+}
@@ -1,6 +1,8 @@
-use parse_display::{Display, FromStr};
+use aoc_common::{parse_number, ParseError};
+use parse_display::Display;
+use std::str::FromStr;
 
-#[derive(Display, FromStr, Clone, Debug)]
+#[derive(Display, PartialEq, Clone, Debug)]
 #[display("{name}: {a}-{b} or {c}-{d}")]
 pub struct TicketField {
     name: String,
@@ -20,11 +22,45 @@ impl TicketField {
     }
 }
 
+impl FromStr for TicketField {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, ranges) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::in_line(s, s, "Expected '<name>: <a>-<b> or <c>-<d>'"))?;
+        let (ab, cd) = ranges
+            .split_once(" or ")
+            .ok_or_else(|| ParseError::in_line(s, ranges, "Expected '<a>-<b> or <c>-<d>'"))?;
+        let (a, b) = parse_range(s, ab)?;
+        let (c, d) = parse_range(s, cd)?;
+
+        Ok(TicketField {
+            name: name.to_string(),
+            a,
+            b,
+            c,
+            d,
+        })
+    }
+}
+
+fn parse_range(line: &str, range: &str) -> Result<(usize, usize), ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::in_line(line, range, "Expected '<start>-<end>'"))?;
+    let number = |part: &str| parse_number(part).map_err(|e| e.within(line, part));
+
+    Ok((number(start)?, number(end)?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_ticket_field_includes() {
         let tf: TicketField = "rico meow: 10-25 or 36-129".parse().unwrap();
         dbg!(&tf);
@@ -32,4 +68,37 @@ mod tests {
         assert_eq!(tf.includes(100), true);
         assert_eq!(tf.includes(30), false);
     }
+
+    #[test]
+    fn test_ticket_field_from_str_errors() {
+        assert_eq!(
+            "rico meow: 10-25 or 36".parse::<TicketField>().unwrap_err(),
+            ParseError::new(1, 21, "36", "Expected '<start>-<end>'")
+        );
+        assert_eq!(
+            "rico meow: 10-x or 36-129"
+                .parse::<TicketField>()
+                .unwrap_err()
+                .column,
+            15
+        );
+        assert_eq!(
+            "rico meow 10-25 or 36-129"
+                .parse::<TicketField>()
+                .unwrap_err()
+                .column,
+            1
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_ticket_field_round_trip(
+            name in "[a-z]+( [a-z]+)*",
+            (a, b, c, d) in any::<(usize, usize, usize, usize)>(),
+        ) {
+            let tf = TicketField { name, a, b, c, d };
+            prop_assert_eq!(tf.to_string().parse::<TicketField>(), Ok(tf));
+        }
+    }
 }
@@ -0,0 +1,168 @@
+use crate::format_ticket;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+
+pub const DEFAULT_SIZE: usize = 240;
+
+const FIELD_NAMES: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+// Each rank of field gets its own band of 50 values: ticket values come from 1-25 within the band,
+// and decoy ranges from 30-45, which no ticket ever uses
+const BAND: usize = 50;
+
+// Notes with 20 fields and size nearby tickets (at least 1), about a fifth of which have an invalid
+// value. The first nearby ticket is always valid, since it takes one valid ticket to tell the
+// columns apart.
+//
+// The fields can be solved by elimination, the way part 2 expects. The fields are given a hidden
+// ranking, and so are the columns; a field accepts every value from its own band upwards, so it's
+// valid for the columns that are ranked the same or higher. The top-ranked field only has one
+// column to choose from, which leaves the next field with only one column, and so on.
+pub fn generate(seed: u64, size: usize) -> String {
+    notes_with_columns(seed, size).0
+}
+
+// The notes, along with the column that each field belongs to
+fn notes_with_columns(seed: u64, size: usize) -> (String, HashMap<String, usize>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let field_count = FIELD_NAMES.len();
+    let max_value = BAND * field_count;
+
+    let mut field_ranks: Vec<usize> = (0..field_count).collect();
+    field_ranks.shuffle(&mut rng);
+    let mut rank_columns: Vec<usize> = (0..field_count).collect();
+    rank_columns.shuffle(&mut rng);
+
+    let fields: Vec<String> = FIELD_NAMES
+        .iter()
+        .zip(&field_ranks)
+        .map(|(name, rank)| {
+            let decoy_start = BAND * rng.gen_range(0..field_count) + rng.gen_range(30..=40);
+            let decoy = (decoy_start, decoy_start + rng.gen_range(0..=5));
+            let valid = (BAND * rank + 1, max_value);
+            let (first, second) = if decoy < valid {
+                (decoy, valid)
+            } else {
+                (valid, decoy)
+            };
+
+            format!(
+                "{}: {}-{} or {}-{}",
+                name, first.0, first.1, second.0, second.1
+            )
+        })
+        .collect();
+
+    let my_ticket = ticket(&mut rng, &rank_columns);
+    let nearby_tickets: Vec<String> = (0..size)
+        .map(|idx| {
+            let mut values = ticket(&mut rng, &rank_columns);
+            if idx > 0 && rng.gen_bool(0.2) {
+                values[rng.gen_range(0..field_count)] = max_value + rng.gen_range(1..=BAND);
+            }
+            format_ticket(&values)
+        })
+        .collect();
+
+    let notes = format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}\n",
+        fields.join("\n"),
+        format_ticket(&my_ticket),
+        nearby_tickets.join("\n")
+    );
+    let columns = FIELD_NAMES
+        .iter()
+        .zip(&field_ranks)
+        .map(|(name, &rank)| (name.to_string(), rank_columns[rank]))
+        .collect();
+
+    (notes, columns)
+}
+
+// A valid ticket, where the column of each rank has a value from that rank's band
+fn ticket(rng: &mut StdRng, rank_columns: &[usize]) -> Vec<usize> {
+    let mut values = vec![0; rank_columns.len()];
+    for (rank, &column) in rank_columns.iter().enumerate() {
+        values[column] = BAND * rank + rng.gen_range(1..=25);
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{solve_fields, Day16};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let notes = Day16::parse(&generate(1, 50)).unwrap();
+        let (_, columns) = notes_with_columns(1, 50);
+        assert_eq!(notes.ticket_fields.len(), 20);
+        assert_eq!(notes.nearby_tickets.len(), 50);
+
+        // Only the values past the last band are invalid
+        let max_value = BAND * FIELD_NAMES.len();
+        let invalid: usize = notes
+            .nearby_tickets
+            .iter()
+            .flatten()
+            .filter(|&&value| value > max_value)
+            .sum();
+        assert!(invalid > 0);
+        assert_eq!(Day16::part_1(&notes), invalid);
+
+        // Elimination finds the column that each field was given
+        let solved = solve_fields(
+            notes.ticket_fields.clone(),
+            notes.nearby_tickets.clone(),
+            &notes.my_ticket,
+        );
+        assert_eq!(solved, columns);
+        let departures: usize = columns
+            .iter()
+            .filter(|(name, _)| name.starts_with("departure"))
+            .map(|(_, &column)| notes.my_ticket[column])
+            .product();
+        assert_eq!(Day16::part_2(&notes), departures);
+
+        // One nearby ticket is still enough to solve the fields
+        let notes = Day16::parse(&generate(1, 0)).unwrap();
+        assert_eq!(notes.nearby_tickets.len(), 1);
+        assert_eq!(Day16::part_1(&notes), 0);
+        let solved = solve_fields(
+            notes.ticket_fields.clone(),
+            notes.nearby_tickets.clone(),
+            &notes.my_ticket,
+        );
+        assert_eq!(solved, notes_with_columns(1, 0).1);
+    }
+}
@@ -0,0 +1,311 @@
+use crate::data::TicketField;
+use aoc_common::{blocks, parse_lines, parse_number, ParseError, Solution};
+use std::collections::{BTreeMap, HashMap};
+
+pub mod data;
+pub mod generator;
+
+pub type Ticket = Vec<usize>;
+
+#[derive(Clone, Debug)]
+pub struct Notes {
+    pub ticket_fields: Vec<TicketField>,
+    pub my_ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Notes;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part_1(notes: &Notes) -> usize {
+        part_1(&notes.ticket_fields, &notes.nearby_tickets)
+    }
+
+    fn part_2(notes: &Notes) -> usize {
+        let notes = notes.clone();
+        part_2(notes.ticket_fields, notes.nearby_tickets, &notes.my_ticket)
+    }
+}
+
+// Identify invalid nearby tickets by considering only whether tickets contain values that are not
+// valid for any field. Adding together all of the invalid values produces your ticket scanning
+// error rate.
+pub fn part_1(ticket_fields: &[TicketField], nearby_tickets: &[Ticket]) -> usize {
+    let mut error_rate = 0;
+
+    for ticket in nearby_tickets {
+        for value in ticket {
+            if !ticket_fields.iter().any(|tf| tf.includes(*value)) {
+                error_rate += value;
+            }
+        }
+    }
+
+    error_rate
+}
+
+// Once you work out which field is which, look for the six fields on your ticket that start with
+// the word departure. What do you get if you multiply those six values together?
+pub fn part_2(
+    ticket_fields: Vec<TicketField>,
+    nearby_tickets: Vec<Ticket>,
+    my_ticket: &[usize],
+) -> usize {
+    let solved_fields = solve_fields(ticket_fields, nearby_tickets, my_ticket);
+    let mut result = 1;
+
+    for (field_name, field_idx) in solved_fields {
+        if field_name.starts_with("departure") {
+            result *= my_ticket[field_idx];
+        }
+    }
+
+    result
+}
+
+pub fn solve_fields(
+    ticket_fields: Vec<TicketField>,
+    mut nearby_tickets: Vec<Ticket>,
+    my_ticket: &[usize],
+) -> HashMap<String, usize> {
+    // Remove invalid tickets
+    nearby_tickets.retain(|ticket| {
+        for value in ticket {
+            if !ticket_fields.iter().any(|tf| tf.includes(*value)) {
+                return false;
+            }
+        }
+        true
+    });
+
+    // Make an inventory of possible field indexes (0, 1, 2, etc) for each TicketField
+    let mut possible_tfs = BTreeMap::new();
+    for tf in &ticket_fields {
+        let mut possible_field_idxs = vec![];
+
+        for val_idx in 0..my_ticket.len() {
+            if nearby_tickets
+                .iter()
+                .all(|t| tf.includes(*t.get(val_idx).unwrap()))
+            {
+                possible_field_idxs.push(val_idx);
+            }
+        }
+
+        possible_tfs.insert(tf.name().clone(), possible_field_idxs);
+    }
+
+    let mut solved_fields = HashMap::new();
+
+    while !possible_tfs.is_empty() {
+        // Find the next TicketField with only one possibility
+        let mut solved = None;
+        for (name, tf_idxs) in possible_tfs.iter() {
+            if tf_idxs.len() == 1 {
+                let idx = *tf_idxs.first().unwrap();
+                solved = Some((name.clone(), idx));
+                solved_fields.insert(name.clone(), idx);
+                break;
+            }
+        }
+        let solved = solved.unwrap();
+
+        // Remove this TicketField and its index from the remaining possibilities
+        possible_tfs.remove(&solved.0).unwrap();
+        for (_name, tf_idxs) in possible_tfs.iter_mut() {
+            tf_idxs.retain(|&idx| idx != solved.1);
+        }
+    }
+
+    solved_fields
+}
+
+// The notes are made up of three sections separated by blank lines: the ticket field rules, "your
+// ticket:" followed by one ticket, and "nearby tickets:" followed by the rest of the tickets.
+pub fn parse_notes(input: &str) -> Result<Notes, ParseError> {
+    let sections = blocks(input);
+    if sections.len() != 3 {
+        return Err(ParseError::new(
+            1,
+            1,
+            "",
+            format!("Expected 3 sections, not {}", sections.len()),
+        ));
+    }
+
+    let (fields_offset, fields) = sections[0];
+    let ticket_fields: Vec<TicketField> =
+        parse_lines(fields, str::parse).map_err(|e| e.offset_lines(fields_offset))?;
+
+    let (my_offset, my) = sections[1];
+    let my_tickets = parse_tickets(my, "your ticket:", ticket_fields.len())
+        .map_err(|e| e.offset_lines(my_offset))?;
+    let my_ticket = match my_tickets.as_slice() {
+        [my_ticket] => my_ticket.clone(),
+        _ => return Err(ParseError::new(my_offset + 2, 1, "", "Expected one ticket")),
+    };
+
+    let (nearby_offset, nearby) = sections[2];
+    let nearby_tickets = parse_tickets(nearby, "nearby tickets:", ticket_fields.len())
+        .map_err(|e| e.offset_lines(nearby_offset))?;
+
+    Ok(Notes {
+        ticket_fields,
+        my_ticket,
+        nearby_tickets,
+    })
+}
+
+// A header line followed by one ticket per line, where every ticket has a value for each field
+fn parse_tickets(
+    section: &str,
+    header: &str,
+    field_count: usize,
+) -> Result<Vec<Ticket>, ParseError> {
+    let mut lines = section.lines();
+    let first_line = lines.next().unwrap_or_default();
+
+    if first_line != header {
+        return Err(ParseError::in_line(
+            first_line,
+            first_line,
+            format!("Expected '{}'", header),
+        ));
+    }
+
+    lines
+        .enumerate()
+        .map(|(idx, line)| {
+            parse_ticket(line)
+                .and_then(|ticket| match ticket.len() {
+                    len if len == field_count => Ok(ticket),
+                    len => Err(ParseError::in_line(
+                        line,
+                        line,
+                        format!("Expected {} values, not {}", field_count, len),
+                    )),
+                })
+                .map_err(|e| e.offset_lines(idx + 1))
+        })
+        .collect()
+}
+
+pub fn parse_ticket(s: &str) -> Result<Ticket, ParseError> {
+    s.split(',')
+        .map(|n| parse_number(n).map_err(|e| e.within(s, n)))
+        .collect()
+}
+
+// Ticket is a plain Vec, so it's written out with this instead of Display
+pub fn format_ticket(ticket: &[usize]) -> String {
+    ticket
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_1() {
+        let ticket_fields = vec![
+            "class: 1-3 or 5-7".parse().unwrap(),
+            "row: 6-11 or 33-44".parse().unwrap(),
+            "seat: 13-40 or 45-50".parse().unwrap(),
+        ];
+        let nearby_tickets = vec![
+            vec![7, 3, 47],
+            vec![40, 4, 50],
+            vec![55, 2, 20],
+            vec![38, 6, 12],
+        ];
+        assert_eq!(part_1(&ticket_fields, &nearby_tickets), 71);
+    }
+
+    fn example_notes() -> &'static str {
+        indoc::indoc! {"
+            class: 1-3 or 5-7
+            row: 6-11 or 33-44
+            seat: 13-40 or 45-50
+
+            your ticket:
+            7,1,14
+
+            nearby tickets:
+            7,3,47
+            40,4,50
+            55,2,20
+            38,6,12
+        "}
+    }
+
+    #[test]
+    fn test_parse_notes() {
+        let notes = parse_notes(example_notes()).unwrap();
+        assert_eq!(notes.ticket_fields.len(), 3);
+        assert_eq!(notes.my_ticket, vec![7, 1, 14]);
+        assert_eq!(notes.nearby_tickets.len(), 4);
+        assert_eq!(part_1(&notes.ticket_fields, &notes.nearby_tickets), 71);
+
+        let error = parse_notes(&example_notes().replace("40,4,50", "40,4,,50")).unwrap_err();
+        assert_eq!((error.line, error.column), (10, 6));
+        let error = parse_notes(&example_notes().replace("55,2,20", "55,2")).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(11, 1, "55,2", "Expected 3 values, not 2")
+        );
+        let error = parse_notes(&example_notes().replace("your ticket", "my ticket")).unwrap_err();
+        assert_eq!(error.line, 5);
+        assert!(parse_notes("class: 1-3 or 5-7").is_err());
+    }
+
+    #[test]
+    fn test_solve_fields() {
+        use std::iter::FromIterator;
+
+        let ticket_fields = vec![
+            "class: 0-1 or 4-19".parse().unwrap(),
+            "row: 0-5 or 8-19".parse().unwrap(),
+            "seat: 0-13 or 16-19".parse().unwrap(),
+        ];
+        let nearby_tickets = vec![vec![3, 9, 18], vec![15, 1, 5], vec![5, 14, 9]];
+        let my_ticket = vec![11, 12, 13];
+
+        let solution = HashMap::<_, _>::from_iter(IntoIterator::into_iter([
+            ("row".to_string(), 0),
+            ("class".to_string(), 1),
+            ("seat".to_string(), 2),
+        ]));
+        assert_eq!(
+            solve_fields(ticket_fields, nearby_tickets, &my_ticket),
+            solution
+        );
+    }
+
+    #[test]
+    fn test_format_ticket() {
+        assert_eq!(format_ticket(&[7, 1, 14]), "7,1,14");
+        assert_eq!(parse_ticket("7,1,14"), Ok(vec![7, 1, 14]));
+    }
+
+    proptest! {
+        #[test]
+        fn test_ticket_round_trip(ticket in prop::collection::vec(any::<usize>(), 1..20)) {
+            prop_assert_eq!(parse_ticket(&format_ticket(&ticket)), Ok(ticket));
+        }
+    }
+}
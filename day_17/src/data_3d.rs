@@ -1,3 +1,4 @@
+use aoc_common::{Grid, ParseError};
 use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -14,20 +15,12 @@ pub struct CubeGrid {
 }
 
 impl CubeGrid {
-    pub fn new(input: &str) -> CubeGrid {
-        let mut active = BTreeSet::new();
-
-        for (y, line) in input.lines().enumerate() {
-            for (x, cube) in line.chars().enumerate() {
-                if cube == '#' {
-                    active.insert(Coord {
-                        x: x as isize,
-                        y: y as isize,
-                        z: 0,
-                    });
-                }
-            }
-        }
+    pub fn new(slice: &Grid<bool>) -> CubeGrid {
+        let active = slice
+            .iter()
+            .filter(|(_coord, &is_active)| is_active)
+            .map(|((x, y), _)| Coord { x, y, z: 0 })
+            .collect();
 
         CubeGrid { active, cycle: 0 }
     }
@@ -78,13 +71,51 @@ impl CubeGrid {
     }
 }
 
+// The initial state is a 2D slice of the grid, where '#' cubes are active and '.' cubes are not
+pub fn parse_slice(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, |cube| match cube {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+// The slice written back out the same way it's read
+pub fn format_slice(slice: &Grid<bool>) -> String {
+    slice
+        .map(|_, &active| if active { '#' } else { '.' })
+        .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_cube_grid_new() {
-        let input = include_str!("input.txt").trim();
-        CubeGrid::new(input);
+        let slice = parse_slice(include_str!("input.txt").trim()).unwrap();
+        assert_eq!(CubeGrid::new(&slice).active_count(), 33);
+    }
+
+    #[test]
+    fn test_parse_slice() {
+        let slice = parse_slice(".#.\n..#\n###").unwrap();
+        assert_eq!(slice.iter().filter(|(_, &active)| active).count(), 5);
+
+        let error = parse_slice(".#.\n.o#").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "o", "Unknown character"));
+    }
+
+    proptest! {
+        #[test]
+        fn test_slice_round_trip(
+            (width, cells) in (1..10_usize, 1..10_usize).prop_flat_map(|(width, height)| {
+                (Just(width), prop::collection::vec(any::<bool>(), width * height))
+            }),
+        ) {
+            let slice = Grid::new(width, cells.len() / width, cells);
+            prop_assert_eq!(parse_slice(&format_slice(&slice)), Ok(slice));
+        }
     }
 }
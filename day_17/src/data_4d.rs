@@ -1,3 +1,4 @@
+use aoc_common::Grid;
 use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -15,21 +16,12 @@ pub struct HyperCubeGrid {
 }
 
 impl HyperCubeGrid {
-    pub fn new(input: &str) -> HyperCubeGrid {
-        let mut active = BTreeSet::new();
-
-        for (y, line) in input.lines().enumerate() {
-            for (x, cube) in line.chars().enumerate() {
-                if cube == '#' {
-                    active.insert(HyperCoord {
-                        x: x as isize,
-                        y: y as isize,
-                        z: 0,
-                        w: 0,
-                    });
-                }
-            }
-        }
+    pub fn new(slice: &Grid<bool>) -> HyperCubeGrid {
+        let active = slice
+            .iter()
+            .filter(|(_coord, &is_active)| is_active)
+            .map(|((x, y), _)| HyperCoord { x, y, z: 0, w: 0 })
+            .collect();
 
         HyperCubeGrid { active, cycle: 0 }
     }
@@ -89,7 +81,7 @@ mod tests {
 
     #[test]
     fn test_cube_grid_new() {
-        let input = include_str!("input.txt").trim();
-        HyperCubeGrid::new(input);
+        let slice = crate::data_3d::parse_slice(include_str!("input.txt").trim()).unwrap();
+        assert_eq!(HyperCubeGrid::new(&slice).active_count(), 33);
     }
 }
@@ -0,0 +1,47 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 8;
+
+// A square initial slice, size cubes along each side, with about half of the cubes active. The
+// active region grows by one cube in every direction each cycle, so even small slices get
+// expensive quickly (especially in 4 dimensions).
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 5), generate(1, 5));
+        assert_ne!(generate(1, 5), generate(2, 5));
+
+        let slice = Day17::parse(&generate(1, 5)).unwrap();
+        assert_eq!((slice.width(), slice.height()), (5, 5));
+        Day17::part_1(&slice);
+
+        let slice = Day17::parse(&generate(1, 20)).unwrap();
+        assert_eq!((slice.width(), slice.height()), (20, 20));
+        let active = slice.iter().filter(|(_, &active)| active).count();
+        assert!(
+            (160..=240).contains(&active),
+            "{} of 400 cubes are active",
+            active
+        );
+    }
+}
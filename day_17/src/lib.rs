@@ -0,0 +1,84 @@
+use crate::data_3d::{parse_slice, CubeGrid};
+use crate::data_4d::HyperCubeGrid;
+use aoc_common::{Grid, ParseError, Solution};
+
+pub mod data_3d;
+pub mod data_4d;
+pub mod generator;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Grid<bool>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        parse_slice(input.trim_end())
+    }
+
+    fn part_1(slice: &Grid<bool>) -> usize {
+        part_1(slice)
+    }
+
+    fn part_2(slice: &Grid<bool>) -> usize {
+        part_2(slice)
+    }
+}
+
+// Starting with your given initial configuration, simulate six cycles. How many cubes are left in
+// the active state after the sixth cycle?
+pub fn part_1(slice: &Grid<bool>) -> usize {
+    let mut cube_grid = CubeGrid::new(slice);
+    cube_grid.run_cycles(6);
+    cube_grid.active_count()
+}
+
+// Starting with your given initial configuration, simulate six cycles in a 4-dimensional space.
+// How many cubes are left in the active state after the sixth cycle?
+pub fn part_2(slice: &Grid<bool>) -> usize {
+    let mut cube_grid = HyperCubeGrid::new(slice);
+    cube_grid.run_cycles(6);
+    cube_grid.active_count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let slice = parse_slice(".#.\n..#\n###").unwrap();
+
+        let mut cube_grid = CubeGrid::new(&slice);
+        assert_eq!(cube_grid.active_count(), 5);
+
+        cube_grid.run_cycles(1);
+        assert_eq!(cube_grid.active_count(), 11);
+
+        cube_grid.run_cycles(1);
+        assert_eq!(cube_grid.active_count(), 21);
+
+        cube_grid.run_cycles(1);
+        assert_eq!(cube_grid.active_count(), 38);
+
+        cube_grid.run_cycles(3);
+        assert_eq!(cube_grid.active_count(), 112);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let slice = parse_slice(".#.\n..#\n###").unwrap();
+
+        let mut cube_grid = HyperCubeGrid::new(&slice);
+        assert_eq!(cube_grid.active_count(), 5);
+
+        cube_grid.run_cycles(1);
+        assert_eq!(cube_grid.active_count(), 29);
+
+        cube_grid.run_cycles(5);
+        assert_eq!(cube_grid.active_count(), 848);
+    }
+}
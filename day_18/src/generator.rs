@@ -0,0 +1,88 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const DEFAULT_SIZE: usize = 380;
+
+// An expression's value is always less than the product of (n + 1) over its numbers n, however
+// it's evaluated, so capping expressions at 10 single digit numbers keeps each line below 10^10
+const MAX_NUMBERS: usize = 10;
+const MAX_DEPTH: usize = 2;
+
+// size lines of homework, with parentheses nested up to 2 deep
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| format!("{}\n", expression(&mut rng, 0, MAX_NUMBERS).0))
+        .collect()
+}
+
+// An expression using at most max_numbers numbers (at least 2), along with how many it used
+fn expression(rng: &mut StdRng, depth: usize, max_numbers: usize) -> (String, usize) {
+    let terms = rng.gen_range(2..=max_numbers.min(4));
+    let mut output = String::new();
+    let mut used = 0;
+
+    for term in 0..terms {
+        if term > 0 {
+            output.push_str(if rng.gen_bool(0.5) { " + " } else { " * " });
+        }
+
+        // Every term still to come needs at least one number
+        let available = max_numbers - used - (terms - term - 1);
+        if depth < MAX_DEPTH && available >= 2 && rng.gen_bool(0.3) {
+            let max_numbers = rng.gen_range(2..=available);
+            let (inner, inner_used) = expression(rng, depth + 1, max_numbers);
+            output.push_str(&format!("({})", inner));
+            used += inner_used;
+        } else {
+            output.push_str(&rng.gen_range(1..=9).to_string());
+            used += 1;
+        }
+    }
+
+    (output, used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(1, 50), generate(1, 50));
+        assert_ne!(generate(1, 50), generate(2, 50));
+
+        let lines = Day18::parse(&generate(1, 50)).unwrap();
+        assert_eq!(lines.len(), 50);
+        assert!(lines.iter().any(|line| line.contains('(')));
+
+        for line in &lines {
+            // Up to 10 numbers, all single digits other than 0
+            let numbers: Vec<&str> = line
+                .split(|c: char| !c.is_ascii_digit())
+                .filter(|number| !number.is_empty())
+                .collect();
+            assert!(numbers.len() <= MAX_NUMBERS);
+            assert!(numbers
+                .iter()
+                .all(|number| number.len() == 1 && *number != "0"));
+
+            // Parentheses nested up to 2 deep
+            let mut depth = 0;
+            for c in line.chars() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => (),
+                }
+                assert!(depth <= MAX_DEPTH);
+            }
+
+            assert!(crate::resolve_expression_part_1(line) < 10usize.pow(10));
+            assert!(crate::resolve_expression_part_2(line) < 10usize.pow(10));
+        }
+    }
+}
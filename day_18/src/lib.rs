@@ -0,0 +1,342 @@
+use aoc_common::{parse_lines, parse_number, ParseError, Solution};
+use Op::*;
+
+pub mod generator;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const INPUT_PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_lines(input, |line| {
+            validate_expression(line)?;
+            Ok(line.to_string())
+        })
+    }
+
+    fn part_1(lines: &Vec<String>) -> usize {
+        part_1(lines)
+    }
+
+    fn part_2(lines: &Vec<String>) -> usize {
+        part_2(lines)
+    }
+}
+
+// Evaluate the expression on each line of the homework; what is the sum of the resulting values?
+pub fn part_1(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|line| resolve_expression_part_1(line))
+        .sum()
+}
+
+pub fn part_2(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|line| resolve_expression_part_2(line))
+        .sum()
+}
+
+// The resolvers assume a well-formed expression: numbers and parenthesised expressions separated by
+// " + " or " * ", with nothing but the parentheses between them. Numbers have to fit in a usize and
+// can't have leading zeros, since the resolvers write results back into the expression as text.
+pub fn validate_expression(line: &str) -> Result<(), ParseError> {
+    let mut chars = line.char_indices().peekable();
+    let mut open_parens = vec![];
+    let mut expecting_operand = true;
+    let char_at = |idx: usize, c: char| &line[idx..idx + c.len_utf8()];
+
+    while let Some((idx, c)) = chars.next() {
+        if expecting_operand {
+            match c {
+                '(' => open_parens.push(idx),
+                '0'..='9' => {
+                    while chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {}
+                    let end = chars.peek().map_or(line.len(), |&(end, _)| end);
+                    let number = &line[idx..end];
+
+                    if number.len() > 1 && number.starts_with('0') {
+                        return Err(ParseError::in_line(line, number, "Unexpected leading zero"));
+                    }
+                    parse_number::<usize>(number).map_err(|e| e.within(line, number))?;
+                    expecting_operand = false;
+                }
+                _ => {
+                    return Err(ParseError::in_line(
+                        line,
+                        char_at(idx, c),
+                        "Expected a number or '('",
+                    ))
+                }
+            }
+        } else {
+            match c {
+                ')' => {
+                    if open_parens.pop().is_none() {
+                        return Err(ParseError::in_line(line, char_at(idx, c), "Unmatched ')'"));
+                    }
+                }
+                ' ' if matches!(line.get(idx..idx + 3), Some(" + ") | Some(" * ")) => {
+                    chars.nth(1);
+                    expecting_operand = true;
+                }
+                _ => {
+                    return Err(ParseError::in_line(
+                        line,
+                        char_at(idx, c),
+                        "Expected ' + ', ' * ' or ')'",
+                    ))
+                }
+            }
+        }
+    }
+
+    if expecting_operand {
+        Err(ParseError::in_line(
+            line,
+            &line[line.len()..],
+            "Expected a number or '('",
+        ))
+    } else if let Some(&idx) = open_parens.last() {
+        Err(ParseError::in_line(line, &line[idx..=idx], "Unclosed '('"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum Op {
+    Add,
+    Multiply,
+}
+
+fn parse_int(term: &str) -> usize {
+    term.parse()
+        .unwrap_or_else(|_| panic!("Expected an integer, not '{}'", term))
+}
+
+// Rather than evaluating multiplication before addition, the operators have the same precedence,
+// and are evaluated left-to-right regardless of the order in which they appear. Parentheses can
+// override this order.
+pub fn resolve_expression_part_1(exp: &str) -> usize {
+    let mut exp = exp.to_string();
+
+    if exp.contains('(') {
+        let paren_start = exp.find('(').unwrap();
+        let mut paren_end = paren_start;
+
+        let mut paren_depth = 1;
+        for (i, c) in exp.chars().skip(paren_start + 1).enumerate() {
+            match c {
+                '(' => paren_depth += 1,
+                ')' => paren_depth -= 1,
+                _ => (),
+            }
+            if paren_depth == 0 {
+                paren_end += i + 1;
+                break;
+            }
+        }
+
+        let res = resolve_expression_part_1(&exp[(paren_start + 1)..=(paren_end - 1)]);
+        exp.replace_range(paren_start..=paren_end, &res.to_string());
+        resolve_expression_part_1(&exp)
+    } else {
+        let mut total = 0;
+        let mut current_op = None;
+
+        for (i, term) in exp.split(' ').enumerate() {
+            if i == 0 {
+                // First time through, just start the total with the first nmber
+                total = parse_int(term);
+            } else {
+                match term {
+                    "+" => current_op = Some(Add),
+                    "*" => current_op = Some(Multiply),
+                    _ => {
+                        // Perform the operation with this number term
+                        let num = parse_int(term);
+                        match current_op {
+                            Some(Add) => total += num,
+                            Some(Multiply) => total *= num,
+                            None => panic!("Expected an operation, not '{}'", term),
+                        }
+
+                        // Reset the current operation
+                        current_op = None;
+                    }
+                }
+            }
+        }
+
+        total
+    }
+}
+
+// Now, addition and multiplication have different precedence levels, but they're not the ones
+// you're familiar with. Instead, addition is evaluated before multiplication.
+pub fn resolve_expression_part_2(exp: &str) -> usize {
+    let mut exp = exp.to_string();
+
+    if exp.contains('(') {
+        let paren_start = exp.find('(').unwrap();
+        let mut paren_end = paren_start;
+
+        let mut paren_depth = 1;
+        for (i, c) in exp.chars().skip(paren_start + 1).enumerate() {
+            match c {
+                '(' => paren_depth += 1,
+                ')' => paren_depth -= 1,
+                _ => (),
+            }
+            if paren_depth == 0 {
+                paren_end += i + 1;
+                break;
+            }
+        }
+
+        let res = resolve_expression_part_2(&exp[(paren_start + 1)..=(paren_end - 1)]);
+        exp.replace_range(paren_start..=paren_end, &res.to_string());
+        resolve_expression_part_2(&exp)
+    } else if exp.contains('+') {
+        let addition_idx = exp.find('+').unwrap();
+        let (left, right) = exp.split_at(addition_idx);
+        let left_num = parse_int(left.trim().split(' ').next_back().unwrap());
+        let right_num = parse_int(right[1..].trim().split(' ').next().unwrap());
+        let res = left_num + right_num;
+
+        exp = exp.replacen(
+            &format!("{} + {}", left_num, right_num),
+            &format!("{}", res),
+            1,
+        );
+        resolve_expression_part_2(&exp)
+    } else {
+        exp.split(" * ")
+            .map(parse_int)
+            .reduce(|res, val| res * val)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Each line is kept as it's written, so any well-formed expression should be read back as-is
+    fn expression() -> impl Strategy<Value = String> {
+        let number = any::<u32>().prop_map(|n| n.to_string());
+        number.prop_recursive(3, 20, 4, |inner| {
+            let op = prop::sample::select(vec![" + ", " * "]);
+            (prop::collection::vec((inner.clone(), op), 1..4), inner).prop_map(|(terms, last)| {
+                let mut exp = String::new();
+                for (term, op) in terms {
+                    exp.push_str(&format!("({}){}", term, op));
+                }
+                exp + &last
+            })
+        })
+    }
+
+    #[test]
+    fn test_validate_expression() {
+        assert_eq!(validate_expression("1 + (2 * 3) + (4 * (5 + 6))"), Ok(()));
+        assert_eq!(validate_expression("12 * 345"), Ok(()));
+
+        assert_eq!(
+            validate_expression("1 + 2 *"),
+            Err(ParseError::new(1, 6, " ", "Expected ' + ', ' * ' or ')'"))
+        );
+        assert_eq!(
+            validate_expression("1 + 2 * "),
+            Err(ParseError::new(1, 9, "", "Expected a number or '('"))
+        );
+        assert_eq!(
+            validate_expression("1 - 2"),
+            Err(ParseError::new(1, 2, " ", "Expected ' + ', ' * ' or ')'"))
+        );
+        assert_eq!(
+            validate_expression("1 + x"),
+            Err(ParseError::new(1, 5, "x", "Expected a number or '('"))
+        );
+        assert_eq!(
+            validate_expression("(1 + 2))"),
+            Err(ParseError::new(1, 8, ")", "Unmatched ')'"))
+        );
+        assert_eq!(
+            validate_expression("1 + ((2 * 3)"),
+            Err(ParseError::new(1, 5, "(", "Unclosed '('"))
+        );
+        assert_eq!(
+            validate_expression("1 + 02"),
+            Err(ParseError::new(1, 5, "02", "Unexpected leading zero"))
+        );
+        assert_eq!(validate_expression("0 * (10 + 0)"), Ok(()));
+
+        let error = validate_expression("99999999999999999999 + 1").unwrap_err();
+        assert_eq!(
+            (error.column, error.text.as_str()),
+            (1, "99999999999999999999")
+        );
+        assert!(Day18::parse("99999999999999999999 + 1").is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Day18::parse("1 + 2\n3 * 4\n").unwrap().len(), 2);
+        assert_eq!(Day18::parse("1 + 2\n3 *4\n").unwrap_err().line, 2);
+    }
+
+    proptest! {
+        #[test]
+        fn test_expression_round_trip(line in expression()) {
+            prop_assert_eq!(Day18::parse(&line), Ok(vec![line]));
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(resolve_expression_part_1("1 + 2 * 3 + 4 * 5 + 6"), 71);
+        assert_eq!(resolve_expression_part_1("1 + (2 * 3) + (4 * (5 + 6))"), 51);
+        assert_eq!(resolve_expression_part_1("2 * 3 + (4 * 5)"), 26);
+        assert_eq!(
+            resolve_expression_part_1("5 + (8 * 3 + 9 + 3 * 4 * 3)"),
+            437
+        );
+        assert_eq!(
+            resolve_expression_part_1("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            12240
+        );
+        assert_eq!(
+            resolve_expression_part_1("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            13632
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(resolve_expression_part_2("2 * 3 * 4"), 24);
+        assert_eq!(resolve_expression_part_2("1 + 2 * 3 + 4 * 5 + 6"), 231);
+        assert_eq!(resolve_expression_part_2("1 + (2 * 3) + (4 * (5 + 6))"), 51);
+        assert_eq!(resolve_expression_part_2("2 * 3 + (4 * 5)"), 46);
+        assert_eq!(
+            resolve_expression_part_2("5 + (8 * 3 + 9 + 3 * 4 * 3)"),
+            1445
+        );
+        assert_eq!(
+            resolve_expression_part_2("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            669060
+        );
+        assert_eq!(
+            resolve_expression_part_2("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            23340
+        );
+    }
+}